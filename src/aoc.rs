@@ -0,0 +1,275 @@
+use advent_of_code_2024::{
+    bench::{self, Benchmark},
+    days::{find_day, Day, Part, DAYS},
+    render, try_read_input,
+};
+use anyhow::Context;
+use clap::{builder::RangedU64ValueParser, Parser, Subcommand};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day or of every day
+    Run {
+        /// The day to run, or `all`
+        day: DaySelection,
+        /// Only run the given part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file to use instead of `input/dayN.txt`, or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of a single day or of every day
+    Bench {
+        /// The day to benchmark, or `all`
+        day: DaySelection,
+        /// Number of times to run each phase
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Input file to use instead of `input/dayN.txt`, or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Draw a day's grid in colour, highlighting what the given part looks for
+    Show {
+        /// The day to show
+        day: u8,
+        /// The part whose cells to highlight (1 or 2)
+        #[arg(long, default_value = "1")]
+        part: Part,
+        /// Input file to use instead of `input/dayN.txt`, or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Reveal the highlighted cells step by step instead of all at once
+        #[arg(long)]
+        animate: bool,
+        /// Frames per second when animating
+        #[arg(long, default_value_t = 30.0)]
+        fps: f64,
+        /// Number of cells revealed per frame when animating
+        #[arg(long, default_value_t = 1)]
+        cells_per_frame: usize,
+    },
+    /// Save a day's grid and highlighted cells as an SVG or PNG image
+    Export {
+        /// The day to draw
+        day: u8,
+        /// The part whose cells to highlight (1 or 2)
+        #[arg(long, default_value = "1")]
+        part: Part,
+        /// Input file to use instead of `input/dayN.txt`, or `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Image to write, as PNG if it ends in `.png` and as SVG otherwise
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Side length of each cell, in pixels
+        #[arg(long, default_value_t = 8, value_parser = RangedU64ValueParser::<u32>::new().range(1..=256))]
+        cell_size: u32,
+    },
+    /// Print a random input for a day, made from a seed so it can be reproduced
+    Generate {
+        /// The day to generate an input for
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Number of lines, or side length of the grid, instead of that of the real input
+        #[arg(long, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        size: Option<usize>,
+    },
+}
+
+#[derive(Copy, Clone, Debug)]
+enum DaySelection {
+    All,
+    Single(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        let number = s
+            .parse::<u8>()
+            .with_context(|| format!("Day should be a number or `all`, got {s}"))?;
+        Ok(Self::Single(number))
+    }
+}
+
+fn select_days(
+    day: DaySelection,
+    input: Option<PathBuf>,
+) -> anyhow::Result<Vec<(&'static Day, PathBuf)>> {
+    match day {
+        DaySelection::All => {
+            anyhow::ensure!(
+                input.is_none(),
+                "--input cannot be used when running all days"
+            );
+            Ok(DAYS
+                .iter()
+                .map(|day| (day, day.default_input_path()))
+                .collect())
+        }
+        DaySelection::Single(number) => {
+            let day =
+                find_day(number).with_context(|| format!("Day {number} is not implemented"))?;
+            let input_path = input.unwrap_or(day.default_input_path());
+            Ok(vec![(day, input_path)])
+        }
+    }
+}
+
+fn failed_on(day: &Day, input_path: &Path) -> String {
+    format!(
+        "Failed to solve day {} using {}",
+        day.number,
+        input_path.display()
+    )
+}
+
+fn run_day(day: &Day, parts: &[Part], input_path: &Path) -> anyhow::Result<()> {
+    let input = try_read_input(input_path)?;
+    let answers = day
+        .solve(&input, parts)
+        .with_context(|| failed_on(day, input_path))?;
+    for (part, answer) in parts.iter().zip(answers) {
+        println!("Day {}, part {part}: {answer}", day.number);
+    }
+
+    Ok(())
+}
+
+fn bench_day(day: &Day, iterations: usize, input_path: &Path) -> anyhow::Result<Benchmark> {
+    let input = try_read_input(input_path)?;
+    day.benchmark(&input, iterations)
+        .with_context(|| failed_on(day, input_path))
+}
+
+fn show_day(
+    day: &Day,
+    part: Part,
+    input_path: &Path,
+    animation: Option<(f64, usize)>,
+) -> anyhow::Result<()> {
+    let input = try_read_input(input_path)?;
+    let renderer = day
+        .visualize(&input, part)
+        .with_context(|| format!("Day {} has no grid to show", day.number))?
+        .with_context(|| failed_on(day, input_path))?;
+
+    match animation {
+        Some((fps, cells_per_frame)) => {
+            render::animate(renderer.frames(cells_per_frame), fps, &mut io::stdout())?
+        }
+        None => print!("{}", renderer.render()),
+    }
+
+    Ok(())
+}
+
+fn export_day(
+    day: &Day,
+    part: Part,
+    input_path: &Path,
+    output: &Path,
+    cell_size: u32,
+) -> anyhow::Result<()> {
+    let input = try_read_input(input_path)?;
+    let image = day
+        .draw(&input, part)
+        .with_context(|| format!("Day {} has no grid to draw", day.number))?
+        .with_context(|| failed_on(day, input_path))?;
+
+    image
+        .with_cell_size(cell_size)
+        .save(output)
+        .with_context(|| format!("Failed to write {}", output.display()))
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+
+            for (day, input_path) in select_days(day, input)? {
+                run_day(day, &parts, &input_path)?;
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            input,
+            json,
+        } => {
+            let days = select_days(day, input)?;
+
+            if json {
+                let benchmarks = days
+                    .into_iter()
+                    .map(|(day, input_path)| bench_day(day, iterations, &input_path))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                println!("{}", serde_json::to_string_pretty(&benchmarks)?);
+            } else {
+                println!("{}", bench::HEADER);
+                for (day, input_path) in days {
+                    println!("{}", bench_day(day, iterations, &input_path)?);
+                }
+            }
+        }
+        Command::Show {
+            day,
+            part,
+            input,
+            animate,
+            fps,
+            cells_per_frame,
+        } => {
+            anyhow::ensure!(fps > 0.0, "--fps should be positive, got {fps}");
+            let day = find_day(day).with_context(|| format!("Day {day} is not implemented"))?;
+            let input_path = input.unwrap_or(day.default_input_path());
+            let animation = animate.then_some((fps, cells_per_frame));
+            show_day(day, part, &input_path, animation)?;
+        }
+        Command::Export {
+            day,
+            part,
+            input,
+            output,
+            cell_size,
+        } => {
+            let day = find_day(day).with_context(|| format!("Day {day} is not implemented"))?;
+            let input_path = input.unwrap_or(day.default_input_path());
+            export_day(day, part, &input_path, &output, cell_size)?;
+        }
+        Command::Generate { day, seed, size } => {
+            let day = find_day(day).with_context(|| format!("Day {day} is not implemented"))?;
+            print!("{}", day.generate(seed, size));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use crate::days::Solution;
+use serde::Serialize;
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Summary of the durations measured for a single phase, in nanoseconds.
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Stats {
+    fn from_durations(mut durations: Vec<Duration>) -> Self {
+        assert!(
+            !durations.is_empty(),
+            "Should have at least one measurement"
+        );
+        durations.sort_unstable();
+
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        let len = durations.len();
+        let median = if len.is_multiple_of(2) {
+            (nanos(durations[len / 2 - 1]) + nanos(durations[len / 2])) / 2
+        } else {
+            nanos(durations[len / 2])
+        };
+        let total = durations.iter().copied().map(nanos).sum::<u64>();
+
+        Self {
+            min_ns: nanos(durations[0]),
+            median_ns: median,
+            mean_ns: total / len as u64,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let min = Duration::from_nanos(self.min_ns);
+        let median = Duration::from_nanos(self.median_ns);
+        let mean = Duration::from_nanos(self.mean_ns);
+        write!(f, "{min:>12.2?} {median:>12.2?} {mean:>12.2?}")
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Benchmark {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl fmt::Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phases = [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ];
+        for (i, (phase, stats)) in phases.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:>3} {phase:<6} {stats}", self.day)?;
+        }
+        Ok(())
+    }
+}
+
+pub const HEADER: &str = "day phase           min       median         mean";
+
+/// Times parsing and both parts of `S` separately, `iterations` times each.
+pub(crate) fn run<S: Solution>(input: &str, iterations: usize) -> anyhow::Result<Benchmark> {
+    anyhow::ensure!(iterations > 0, "Should run at least one iteration");
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        part2.push(start.elapsed());
+    }
+
+    Ok(Benchmark {
+        day: S::DAY,
+        iterations,
+        parse: Stats::from_durations(parse),
+        part1: Stats::from_durations(part1),
+        part2: Stats::from_durations(part2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::{Day1, EXAMPLE};
+
+    fn stats_of(nanos: &[u64]) -> Stats {
+        Stats::from_durations(nanos.iter().copied().map(Duration::from_nanos).collect())
+    }
+
+    #[test]
+    fn median_of_odd_number_of_samples() {
+        assert_eq!(stats_of(&[30, 10, 20]).median_ns, 20);
+    }
+
+    #[test]
+    fn median_of_even_number_of_samples() {
+        assert_eq!(stats_of(&[40, 10, 30, 20]).median_ns, 25);
+    }
+
+    #[test]
+    fn min_and_mean() {
+        let stats = stats_of(&[50, 10, 30, 20]);
+        assert_eq!(stats.min_ns, 10);
+        assert_eq!(stats.mean_ns, 27);
+    }
+
+    #[test]
+    fn needs_at_least_one_iteration() {
+        assert!(run::<Day1>(EXAMPLE, 0).is_err());
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let benchmark = run::<Day1>(EXAMPLE, 3).unwrap();
+        let json = serde_json::to_value(&benchmark).unwrap();
+
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["iterations"], 3);
+        for phase in ["parse", "part1", "part2"] {
+            let stats = json[phase].as_object().unwrap();
+            let mut keys = stats.keys().collect::<Vec<_>>();
+            keys.sort();
+            assert_eq!(keys, ["mean_ns", "median_ns", "min_ns"]);
+            assert!(stats.values().all(serde_json::Value::is_u64));
+        }
+    }
+}
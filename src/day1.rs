@@ -1,41 +1,101 @@
-use std::collections::HashMap;
-use itertools::Itertools;
-use advent_of_code_2024::read_file_lines_as;
+use advent_of_code_2024::{
+    days::{
+        day1::{
+            explain,
+            matching::{self, Cost},
+            matrix, streaming, Day1,
+        },
+        DayArgs, Solution,
+    },
+    ArrayFormat,
+};
+use clap::Parser;
+use std::io;
 
-fn calculate_total_distance(first_column: &Vec<u64>, second_column: &Vec<u64>) -> u64 {
-    first_column.iter().sorted().zip(second_column.iter().sorted()).map(|(x, y)| x.abs_diff(*y)).sum()
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solution for day 1")]
+struct Args {
+    #[command(flatten)]
+    common: DayArgs,
+    /// Sort the location lists on disk, for inputs too large to fit in memory
+    #[arg(long)]
+    streaming: bool,
+    /// Number of locations per column to sort in memory at a time when streaming
+    #[arg(long, default_value_t = streaming::DEFAULT_CHUNK_LEN)]
+    chunk_len: usize,
+    /// Compare every pair of columns instead of just the first two
+    #[arg(long, conflicts_with = "streaming")]
+    matrix: bool,
+    /// Print the comparison of every pair of columns as CSV
+    #[arg(long, requires = "matrix")]
+    csv: bool,
+    /// Pair up the first two columns as cheaply as possible under a cost function: absolute,
+    /// squared, capped=CAP or weighted=ABOVE,BELOW
+    #[arg(long, value_name = "COST", conflicts_with_all = ["streaming", "matrix"])]
+    matching: Option<Cost>,
+    /// Show the sorted pairs and the frequency of each location behind the two answers
+    #[arg(long, conflicts_with_all = ["streaming", "matrix", "matching"])]
+    explain: bool,
+    /// Print the explanation as JSON
+    #[arg(long, requires = "explain")]
+    json: bool,
 }
 
-fn calculate_similarity_score(first_column: &Vec<u64>, second_column: &Vec<u64>) -> u64 {
-    let freq_map = second_column.iter().fold(HashMap::new(), |mut acc, &elem| {
-        *acc.entry(elem).or_insert(0u64) += 1;
-        acc
-    });
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
 
-    first_column.iter().fold(0, |acc, &elem| {
-        acc + elem * freq_map.get(&elem).unwrap_or(&0)
-    })
-}
-
-fn main() {
-    let input  = read_file_lines_as("input/day1.txt", |line| {
-        let mut row = line.split_whitespace().map(|elem| elem.parse::<u64>().expect("elem should be a number"));
-
-        (row.next().expect("row should have 2 elements"), row.next().expect("row should have 2 elements"))
-    });
-
-    let (first_column, second_column): (Vec<_>, Vec<_>) = input.into_iter().unzip();
-    let total_distance = calculate_total_distance(&first_column, &second_column);
-
-    println!("The total distance is: {total_distance}");
+    if args.matrix {
+        let columns = Day1::parse(&args.common.read_input(Day1::DAY)?)?;
+        let scores = matrix::calculate_pairwise_scores(&columns);
+        if args.csv {
+            scores.write_csv(&mut io::stdout().lock())?;
+        } else {
+            let format = ArrayFormat::new()
+                .with_delimiter(" ")
+                .with_borders()
+                .with_headers();
+            println!("Total distances:\n{}", format.display(&scores.distances));
+            print!(
+                "Similarity scores:\n{}",
+                format.display(&scores.similarities)
+            );
+        }
+        return Ok(());
+    }
 
-    let mut freq_map = HashMap::new();
+    if args.explain {
+        let columns = Day1::parse(&args.common.read_input(Day1::DAY)?)?;
+        let explanation = explain::explain(&columns[0], &columns[1]);
+        if args.json {
+            println!("{}", serde_json::to_string_pretty(&explanation)?);
+        } else {
+            println!("{explanation}");
+        }
+        return Ok(());
+    }
 
-    for &item in &second_column {
-        *freq_map.entry(item).or_insert(0u64) += 1;
+    if let Some(cost) = args.matching {
+        let columns = Day1::parse(&args.common.read_input(Day1::DAY)?)?;
+        let matching = matching::find_optimal_matching(&columns[0], &columns[1], cost)?;
+        for &(first, second) in &matching.pairs {
+            let pair_cost = cost
+                .of(first, second)
+                .expect("Costs were checked when matching");
+            println!("{first} {second} {pair_cost}");
+        }
+        println!("The total {cost} cost is: {}", matching.total_cost);
+        return Ok(());
     }
 
-    let similarity_score = calculate_similarity_score(&first_column, &second_column);
+    let (total_distance, similarity_score) = if args.streaming {
+        streaming::solve(args.common.open_input(Day1::DAY)?, args.chunk_len)?
+    } else {
+        let columns = Day1::parse(&args.common.read_input(Day1::DAY)?)?;
+        (Day1::part1(&columns), Day1::part2(&columns))
+    };
 
+    println!("The total distance is: {total_distance}");
     println!("The similarity score is: {similarity_score}");
-}
\ No newline at end of file
+
+    Ok(())
+}
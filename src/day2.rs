@@ -1,78 +1,31 @@
-use advent_of_code_2024::read_file_lines_as;
-use itertools::Itertools;
-
-#[derive(Debug)]
-struct Report {
-    levels: Vec<i32>,
+use advent_of_code_2024::days::{
+    day2::{self, Day2, SafetyPolicy},
+    DayArgs, Solution,
+};
+use clap::{error::ErrorKind, CommandFactory, Parser};
+
+#[derive(Parser)]
+#[command(name = "day2", about = "Advent of Code 2024 solution for day 2")]
+struct Args {
+    #[command(flatten)]
+    common: DayArgs,
+    #[command(flatten)]
+    policy: SafetyPolicy,
 }
 
-impl Report {
-    fn new(levels: Vec<i32>) -> Self {
-        Self { levels }
-    }
-
-    fn with_level_removed(&self, idx: usize) -> Self {
-        let new_levels = self
-            .levels
-            .iter()
-            .copied()
-            .enumerate()
-            .filter(|(i, _)| *i != idx)
-            .map(|(_, l)| l)
-            .collect();
-
-        Self { levels: new_levels }
-    }
-
-    fn is_safe(&self) -> bool {
-        if self.levels.len() < 2 {
-            return true;
-        }
-
-        let first = self.levels[0];
-        let second = self.levels[1];
-
-        if first == second {
-            return false;
-        }
-
-        let ascending = first < second;
-
-        !self.levels.iter().tuple_windows().any(|(&prev, &current)| {
-            (ascending && ((current - prev) < 1 || (current - prev) > 3))
-                || (!ascending && ((prev - current) < 1 || (prev - current) > 3))
-        })
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    if let Err(e) = args.policy.validate() {
+        Args::command().error(ErrorKind::ValueValidation, e).exit();
     }
+    let input = Day2::parse(&args.common.read_input(Day2::DAY)?)?;
 
-    fn is_safe_with_dampener(&self) -> bool {
-        (0..self.levels.len())
-            .map(|i| self.with_level_removed(i))
-            .any(|report| report.is_safe())
-    }
-}
-
-fn calculate_num_of_safe_reports(reports: &[Report]) -> usize {
-    reports.iter().filter(|report| report.is_safe()).count()
-}
-
-fn calculate_num_of_safe_reports_with_dampener(reports: &[Report]) -> usize {
-    reports
-        .iter()
-        .filter(|report| report.is_safe_with_dampener())
-        .count()
-}
-
-fn main() {
-    let reports = read_file_lines_as("input/day2.txt", |line| {
-        let levels = line
-            .split_whitespace()
-            .map(|v| v.parse().expect("value should be a number"))
-            .collect();
-        Report::new(levels)
-    });
-    let num_of_safe_reports = calculate_num_of_safe_reports(&reports);
+    let num_of_safe_reports = day2::calculate_num_of_safe_reports(&input, &args.policy);
     println!("The number of safe reports is {num_of_safe_reports}");
 
-    let num_of_safe_reports_with_dampener = calculate_num_of_safe_reports_with_dampener(&reports);
-    println!("The number of safe reports with dampener is {num_of_safe_reports_with_dampener}")
+    let num_of_safe_reports_with_dampener =
+        day2::calculate_num_of_safe_reports_with_dampener(&input, &args.policy);
+    println!("The number of safe reports with dampener is {num_of_safe_reports_with_dampener}");
+
+    Ok(())
 }
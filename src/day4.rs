@@ -1,199 +1,14 @@
-use advent_of_code_2024::{read_2d_char_array, read_file_to_string, Coords2D};
-use array2d::Array2D;
-use phf::phf_map;
+use advent_of_code_2024::days::{day4::Day4, DayArgs, Solution};
+use clap::Parser;
 
-#[derive(Copy, Clone)]
-enum Direction {
-    Up,
-    UpRight,
-    Right,
-    DownRight,
-    Down,
-    DownLeft,
-    Left,
-    UpLeft,
-}
-
-const DIRECTIONS: [Direction; 8] = [
-    Direction::Up,
-    Direction::UpRight,
-    Direction::Right,
-    Direction::DownRight,
-    Direction::Down,
-    Direction::DownLeft,
-    Direction::Left,
-    Direction::UpLeft,
-];
-
-impl Direction {
-    fn all_directions_iter() -> impl Iterator<Item = Self> {
-        DIRECTIONS.iter().copied()
-    }
-}
-
-const NEXT_MAP: phf::Map<char, char> = phf_map! {
-    'X' => 'M',
-    'M' => 'A',
-    'A' => 'S'
-};
-
-fn step_in_direction(
-    chars: &Array2D<char>,
-    pos: Coords2D,
-    direction: Direction,
-) -> Option<Coords2D> {
-    let num_columns = chars.row_len();
-    let num_rows = chars.column_len();
-
-    match direction {
-        Direction::Up => {
-            if pos.row == 0 {
-                None
-            } else {
-                Some(pos.move_up())
-            }
-        }
-        Direction::UpRight => {
-            if pos.row == 0 || pos.column == num_columns - 1 {
-                None
-            } else {
-                Some(pos.move_up_right())
-            }
-        }
-        Direction::Right => {
-            if pos.column == num_columns - 1 {
-                None
-            } else {
-                Some(pos.move_right())
-            }
-        }
-        Direction::DownRight => {
-            if pos.row == num_rows - 1 || pos.column == num_columns - 1 {
-                None
-            } else {
-                Some(pos.move_down_right())
-            }
-        }
-        Direction::Down => {
-            if pos.row == num_rows - 1 {
-                None
-            } else {
-                Some(pos.move_down())
-            }
-        }
-        Direction::DownLeft => {
-            if pos.row == num_rows - 1 || pos.column == 0 {
-                None
-            } else {
-                Some(pos.move_down_left())
-            }
-        }
-        Direction::Left => {
-            if pos.column == 0 {
-                None
-            } else {
-                Some(pos.move_left())
-            }
-        }
-        Direction::UpLeft => {
-            if pos.row == 0 || pos.column == 0 {
-                None
-            } else {
-                Some(pos.move_up_left())
-            }
-        }
-    }
-}
-
-fn search_word_in_direction_from(
-    chars: &Array2D<char>,
-    current_pos: Coords2D,
-    direction: Direction,
-    char_to_check: char,
-) -> bool {
-    let Some(current_char) = chars.get(current_pos.row, current_pos.column) else {
-        return false;
-    };
-
-    if *current_char != char_to_check {
-        return false;
-    }
-
-    // We've reached the end of the word
-    if char_to_check == 'S' {
-        return true;
-    }
+fn main() -> anyhow::Result<()> {
+    let input = Day4::parse(&DayArgs::parse().read_input(Day4::DAY)?)?;
 
-    let Some(next) = step_in_direction(chars, current_pos, direction) else {
-        return false;
-    };
-    let next_char = NEXT_MAP.get(&char_to_check).unwrap();
-    search_word_in_direction_from(chars, next, direction, *next_char)
-}
-
-fn find_num_of_xmas_in_grid(chars: &Array2D<char>) -> usize {
-    chars
-        .indices_row_major()
-        .map(|(row, column)| {
-            let pos = Coords2D::new(row, column);
-            Direction::all_directions_iter()
-                .filter(|d| search_word_in_direction_from(&chars, pos, *d, 'X'))
-                .count()
-        })
-        .sum()
-}
-
-fn has_cross_mas(chars: &Array2D<char>, pos: Coords2D) -> bool {
-    let Some(current_char) = chars.get(pos.row, pos.column) else {
-        return false;
-    };
-
-    if *current_char != 'A' {
-        return false;
-    };
-
-    let Some(&up_right) =
-        step_in_direction(chars, pos, Direction::UpRight).and_then(|p| chars.get(p.row, p.column))
-    else {
-        return false;
-    };
-
-    let Some(&down_right) = step_in_direction(chars, pos, Direction::DownRight)
-        .and_then(|p| chars.get(p.row, p.column))
-    else {
-        return false;
-    };
-
-    let Some(&down_left) =
-        step_in_direction(chars, pos, Direction::DownLeft).and_then(|p| chars.get(p.row, p.column))
-    else {
-        return false;
-    };
-
-    let Some(&up_left) =
-        step_in_direction(chars, pos, Direction::UpLeft).and_then(|p| chars.get(p.row, p.column))
-    else {
-        return false;
-    };
-
-    let has_first_arm = up_right == 'M' && down_left == 'S' || up_right == 'S' && down_left == 'M';
-    let has_second_arm = up_left == 'M' && down_right == 'S' || up_left == 'S' && down_right == 'M';
-
-    has_first_arm && has_second_arm
-}
-
-fn find_num_of_cross_mas_in_grid(chars: &Array2D<char>) -> usize {
-    chars
-        .indices_row_major()
-        .filter(|(row, column)| has_cross_mas(chars, Coords2D::new(*row, *column)))
-        .count()
-}
-
-fn main() {
-    let chars = read_2d_char_array(&read_file_to_string("input/day4.txt"));
-    let num_of_xmas = find_num_of_xmas_in_grid(&chars);
+    let num_of_xmas = Day4::part1(&input);
     println!("The number of times XMAS appears in the word search is {num_of_xmas}");
 
-    let num_of_cross_mas = find_num_of_cross_mas_in_grid(&chars);
+    let num_of_cross_mas = Day4::part2(&input);
     println!("The number of cross MAS-es in the word search is {num_of_cross_mas}");
+
+    Ok(())
 }
@@ -0,0 +1,164 @@
+pub mod explain;
+pub mod matching;
+pub mod matrix;
+pub mod streaming;
+
+use crate::{days::Solution, generate::Generate, parse::parse_lines, InputError, InputErrorKind};
+use itertools::Itertools;
+use rand::{seq::IndexedRandom, Rng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+
+pub fn calculate_total_distance(first_column: &[u64], second_column: &[u64]) -> u64 {
+    first_column
+        .iter()
+        .sorted()
+        .zip(second_column.iter().sorted())
+        .map(|(x, y)| x.abs_diff(*y))
+        .sum()
+}
+
+pub fn calculate_similarity_score(first_column: &[u64], second_column: &[u64]) -> u64 {
+    let freq_map = second_column.iter().fold(HashMap::new(), |mut acc, &elem| {
+        *acc.entry(elem).or_insert(0u64) += 1;
+        acc
+    });
+
+    first_column.iter().fold(0, |acc, &elem| {
+        acc + elem * freq_map.get(&elem).unwrap_or(&0)
+    })
+}
+
+/// Splits `input` into its columns of locations. Every line needs the same number of locations.
+pub fn parse_columns(input: &str) -> Result<Vec<Vec<u64>>, InputError> {
+    let rows = parse_lines::<(Vec<u64>,)>(input, "{}")?;
+    let num_columns = rows.first().map_or(0, |(row,)| row.len());
+
+    let mut columns = vec![Vec::with_capacity(rows.len()); num_columns];
+    for (i, (row,)) in rows.into_iter().enumerate() {
+        if row.len() != num_columns {
+            let kind = InputErrorKind::RaggedRow {
+                expected: num_columns,
+                found: row.len(),
+            };
+            return Err(InputError::new(kind).at(i + 1, None));
+        }
+        for (column, location) in columns.iter_mut().zip(row) {
+            column.push(location);
+        }
+    }
+
+    Ok(columns)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// Every column of locations, of which the puzzle only uses the first two.
+    type Input = Vec<Vec<u64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let columns = parse_columns(input)?;
+        anyhow::ensure!(
+            columns.len() >= 2,
+            "Input should have at least 2 columns of locations, found {}",
+            columns.len()
+        );
+        Ok(columns)
+    }
+
+    fn part1(columns: &Self::Input) -> Self::Answer1 {
+        calculate_total_distance(&columns[0], &columns[1])
+    }
+
+    fn part2(columns: &Self::Input) -> Self::Answer2 {
+        calculate_similarity_score(&columns[0], &columns[1])
+    }
+}
+
+impl Generate for Day1 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        let first_column = (0..size)
+            .map(|_| rng.random_range(10000..100000))
+            .collect::<Vec<u64>>();
+
+        let mut input = String::new();
+        for &first in &first_column {
+            // Reuse locations from the first column now and then, so there is something to score
+            let second = match rng.random_bool(0.3) {
+                true => *first_column
+                    .choose(rng)
+                    .expect("Column should not be empty"),
+                false => rng.random_range(10000..100000),
+            };
+            input += &format!("{first}   {second}\n");
+        }
+
+        input
+    }
+}
+
+/// The puzzle's example, shared by the tests of every day 1 module.
+#[cfg(test)]
+pub(crate) const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_both_columns() {
+        let columns = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(columns, [[3, 4, 2, 1, 3, 3], [4, 3, 5, 3, 9, 3]]);
+    }
+
+    #[test]
+    fn rows_need_the_same_number_of_columns() {
+        let error = parse_columns("1 2 3\n4 5\n").unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert!(matches!(
+            error.kind,
+            InputErrorKind::RaggedRow {
+                expected: 3,
+                found: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn pairs_smallest_with_smallest() {
+        assert_eq!(calculate_total_distance(&[1], &[3]), 2);
+        assert_eq!(calculate_total_distance(&[3, 1], &[4, 3]), 2 + 1);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day1::part1(&Day1::parse(EXAMPLE).unwrap()), 11);
+    }
+
+    #[test]
+    fn similarity_counts_occurrences_in_second_column() {
+        let columns = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_similarity_score(&[3], &columns[1]), 9);
+        assert_eq!(calculate_similarity_score(&[4], &columns[1]), 4);
+        assert_eq!(calculate_similarity_score(&[2], &columns[1]), 0);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day1::part2(&Day1::parse(EXAMPLE).unwrap()), 31);
+    }
+}
@@ -0,0 +1,138 @@
+//! The intermediate values behind both day 1 answers, for checking them by hand.
+
+use itertools::Itertools;
+use serde::Serialize;
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PairDistance {
+    pub first: u64,
+    pub second: u64,
+    pub distance: u64,
+}
+
+/// How much a single distinct location of the first column adds to the similarity score.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Contribution {
+    pub location: u64,
+    pub count_in_first: u64,
+    pub count_in_second: u64,
+    pub contribution: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    /// The locations of both columns paired up in sorted order.
+    pub pairs: Vec<PairDistance>,
+    pub total_distance: u64,
+    /// One entry per distinct location of the first column, in ascending order.
+    pub contributions: Vec<Contribution>,
+    pub similarity_score: u64,
+}
+
+pub fn explain(first_column: &[u64], second_column: &[u64]) -> Explanation {
+    let pairs = first_column
+        .iter()
+        .sorted()
+        .zip(second_column.iter().sorted())
+        .map(|(&first, &second)| PairDistance {
+            first,
+            second,
+            distance: first.abs_diff(second),
+        })
+        .collect::<Vec<_>>();
+
+    let count = |column: &[u64]| {
+        column.iter().fold(BTreeMap::new(), |mut acc, &location| {
+            *acc.entry(location).or_insert(0u64) += 1;
+            acc
+        })
+    };
+    let second_counts = count(second_column);
+    let contributions = count(first_column)
+        .into_iter()
+        .map(|(location, count_in_first)| {
+            let count_in_second = second_counts.get(&location).copied().unwrap_or(0);
+            Contribution {
+                location,
+                count_in_first,
+                count_in_second,
+                contribution: location * count_in_first * count_in_second,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Explanation {
+        total_distance: pairs.iter().map(|pair| pair.distance).sum(),
+        similarity_score: contributions.iter().map(|c| c.contribution).sum(),
+        pairs,
+        contributions,
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>12} {:>12} {:>12}", "first", "second", "distance")?;
+        for pair in &self.pairs {
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>12}",
+                pair.first, pair.second, pair.distance
+            )?;
+        }
+        writeln!(f, "Total distance: {}", self.total_distance)?;
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "{:>12} {:>12} {:>12} {:>12}",
+            "location", "in first", "in second", "contribution"
+        )?;
+        for c in &self.contributions {
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>12} {:>12}",
+                c.location, c.count_in_first, c.count_in_second, c.contribution
+            )?;
+        }
+        write!(f, "Similarity score: {}", self.similarity_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::{parse_columns, EXAMPLE};
+
+    #[test]
+    fn explains_example() {
+        let columns = parse_columns(EXAMPLE).unwrap();
+        let explanation = explain(&columns[0], &columns[1]);
+
+        let distances = explanation
+            .pairs
+            .iter()
+            .map(|pair| pair.distance)
+            .collect::<Vec<_>>();
+        assert_eq!(distances, [2, 1, 0, 1, 2, 5]);
+        assert_eq!(explanation.total_distance, 11);
+
+        let contributions = explanation
+            .contributions
+            .iter()
+            .map(|c| {
+                (
+                    c.location,
+                    c.count_in_first,
+                    c.count_in_second,
+                    c.contribution,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            contributions,
+            [(1, 1, 0, 0), (2, 1, 0, 0), (3, 3, 3, 27), (4, 1, 1, 4)]
+        );
+        assert_eq!(explanation.similarity_score, 31);
+    }
+}
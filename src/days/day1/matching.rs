@@ -0,0 +1,258 @@
+//! Day 1 with the cheapest pairing of the two location lists under other cost functions than the
+//! absolute difference, for which pairing the sorted lists is not always optimal.
+
+use anyhow::Context;
+use array2d::Array2D;
+use std::{fmt, str::FromStr};
+
+/// The cost of pairing a location from the first list with one from the second.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cost {
+    Absolute,
+    Squared,
+    /// The absolute difference, scaled by `above` if the first location is the larger one and by
+    /// `below` otherwise.
+    Weighted {
+        above: u64,
+        below: u64,
+    },
+    /// The absolute difference, but never more than the cap.
+    Capped(u64),
+}
+
+impl Cost {
+    /// The cost of pairing `first` with `second`, or `None` if it does not fit in a `u64`.
+    pub fn of(self, first: u64, second: u64) -> Option<u64> {
+        let difference = first.abs_diff(second);
+        match self {
+            Self::Absolute => Some(difference),
+            Self::Squared => difference.checked_mul(difference),
+            Self::Weighted { above, below } => match first > second {
+                true => above.checked_mul(difference),
+                false => below.checked_mul(difference),
+            },
+            Self::Capped(cap) => Some(difference.min(cap)),
+        }
+    }
+}
+
+impl fmt::Display for Cost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Absolute => write!(f, "absolute"),
+            Self::Squared => write!(f, "squared"),
+            Self::Weighted { above, below } => write!(f, "weighted={above},{below}"),
+            Self::Capped(cap) => write!(f, "capped={cap}"),
+        }
+    }
+}
+
+impl FromStr for Cost {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            None if s == "absolute" => Ok(Self::Absolute),
+            None if s == "squared" => Ok(Self::Squared),
+            Some(("capped", cap)) => Ok(Self::Capped(cap.parse()?)),
+            Some(("weighted", weights)) => {
+                let (above, below) = weights.split_once(',').ok_or_else(|| {
+                    anyhow::anyhow!("Weights should be given as `weighted=ABOVE,BELOW`")
+                })?;
+                Ok(Self::Weighted {
+                    above: above.parse()?,
+                    below: below.parse()?,
+                })
+            }
+            _ => anyhow::bail!(
+                "Cost should be absolute, squared, capped=CAP or weighted=ABOVE,BELOW, got {s}"
+            ),
+        }
+    }
+}
+
+/// The cheapest way to pair up every location of the first list with one of the second.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matching {
+    /// Pairs of locations from the first and second list, in the order of the first list.
+    pub pairs: Vec<(u64, u64)>,
+    pub total_cost: u64,
+}
+
+/// Finds the minimum-cost assignment of rows to columns in a square matrix with the Hungarian
+/// algorithm, in `O(n³)`. Returns the column assigned to each row. Costs are `i128` so that the
+/// potentials computed from them have plenty of headroom for any `u64` costs.
+pub fn min_cost_assignment(costs: &Array2D<i128>) -> Vec<usize> {
+    let n = costs.num_rows();
+    assert_eq!(n, costs.num_columns(), "Cost matrix should be square");
+
+    // Potentials of rows and columns, and the row assigned to each column. Index 0 is a dummy
+    // column used as the starting point when adding each row.
+    let mut row_potential = vec![0i128; n + 1];
+    let mut column_potential = vec![0i128; n + 1];
+    let mut assigned_row = vec![0usize; n + 1];
+    let mut way = vec![0usize; n + 1];
+
+    for row in 1..=n {
+        assigned_row[0] = row;
+        let mut column = 0;
+        let mut min_slack = vec![i128::MAX; n + 1];
+        let mut used = vec![false; n + 1];
+
+        loop {
+            used[column] = true;
+            let current_row = assigned_row[column];
+            let mut delta = i128::MAX;
+            let mut next_column = 0;
+
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let slack = costs[(current_row - 1, j - 1)]
+                    - row_potential[current_row]
+                    - column_potential[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = column;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    next_column = j;
+                }
+            }
+
+            for j in 0..=n {
+                if used[j] {
+                    row_potential[assigned_row[j]] += delta;
+                    column_potential[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+
+            column = next_column;
+            if assigned_row[column] == 0 {
+                break;
+            }
+        }
+
+        // Flip the alternating path back to the dummy column
+        while column != 0 {
+            let previous = way[column];
+            assigned_row[column] = assigned_row[previous];
+            column = previous;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for column in 1..=n {
+        assignment[assigned_row[column] - 1] = column - 1;
+    }
+    assignment
+}
+
+pub fn find_optimal_matching(
+    first_column: &[u64],
+    second_column: &[u64],
+    cost: Cost,
+) -> anyhow::Result<Matching> {
+    let n = first_column.len();
+    anyhow::ensure!(
+        n == second_column.len(),
+        "Both lists should be as long, found {n} and {}",
+        second_column.len()
+    );
+
+    let pair_cost = |first: u64, second: u64| {
+        cost.of(first, second).ok_or_else(|| {
+            anyhow::anyhow!("The {cost} cost of pairing {first} with {second} is too large")
+        })
+    };
+    let costs = first_column
+        .iter()
+        .flat_map(|&first| {
+            second_column
+                .iter()
+                .map(move |&second| pair_cost(first, second).map(i128::from))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let costs = Array2D::from_row_major(&costs, n, n).expect("There is one cost per pair");
+
+    let pairs = min_cost_assignment(&costs)
+        .into_iter()
+        .enumerate()
+        .map(|(i, j)| (first_column[i], second_column[j]))
+        .collect::<Vec<_>>();
+    let total_cost = pairs.iter().try_fold(0u64, |total, &(first, second)| {
+        total
+            .checked_add(pair_cost(first, second)?)
+            .with_context(|| format!("The total {cost} cost is too large"))
+    })?;
+
+    Ok(Matching { pairs, total_cost })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::{calculate_total_distance, Day1, EXAMPLE};
+    use crate::days::Solution;
+
+    #[test]
+    fn parses_costs() {
+        for cost in [
+            Cost::Absolute,
+            Cost::Squared,
+            Cost::Capped(5),
+            Cost::Weighted { above: 2, below: 3 },
+        ] {
+            assert_eq!(cost.to_string().parse::<Cost>().unwrap(), cost);
+        }
+        assert!("capped".parse::<Cost>().is_err());
+    }
+
+    #[test]
+    fn absolute_cost_matches_sorted_pairing() {
+        let columns = Day1::parse(EXAMPLE).unwrap();
+        let matching = find_optimal_matching(&columns[0], &columns[1], Cost::Absolute).unwrap();
+        assert_eq!(matching.total_cost, 11);
+        assert_eq!(
+            matching.total_cost,
+            calculate_total_distance(&columns[0], &columns[1])
+        );
+    }
+
+    #[test]
+    fn capped_cost_can_beat_sorted_pairing() {
+        // Sorted pairing costs min(9, 5) + min(10, 5), crossing over costs min(20, 5) + min(1, 5)
+        let matching = find_optimal_matching(&[0, 10], &[9, 20], Cost::Capped(5)).unwrap();
+        assert_eq!(matching.pairs, [(0, 20), (10, 9)]);
+        assert_eq!(matching.total_cost, 6);
+    }
+
+    #[test]
+    fn costs_that_do_not_fit() {
+        let huge = 1 << 32;
+        assert_eq!(Cost::Squared.of(0, huge), None);
+        assert_eq!(Cost::Squared.of(0, huge - 1), Some((huge - 1) * (huge - 1)));
+        assert!(find_optimal_matching(&[0], &[huge], Cost::Squared).is_err());
+
+        let weighted = Cost::Weighted {
+            above: u64::MAX,
+            below: 1,
+        };
+        assert!(find_optimal_matching(&[2, 0], &[0, 2], weighted).is_err());
+        assert!(find_optimal_matching(&[0, 1], &[1, 2], weighted).is_ok());
+
+        // Each cost fits, but their sum does not
+        let matching = find_optimal_matching(&[0, 0], &[u64::MAX, u64::MAX], Cost::Absolute);
+        assert!(matching.is_err());
+    }
+
+    #[test]
+    fn assignment_of_small_matrix() {
+        let costs = Array2D::from_rows(&[vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]]).unwrap();
+        assert_eq!(min_cost_assignment(&costs), [1, 0, 2]);
+    }
+}
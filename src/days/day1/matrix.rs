@@ -0,0 +1,129 @@
+//! Day 1 scores between every pair of columns, for location lists with more than two of them.
+
+use array2d::Array2D;
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+/// The total distance and similarity score of every pair of columns, indexed by the numbers of
+/// the two columns. Both are symmetric, so it does not matter which column comes first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairwiseScores {
+    pub distances: Array2D<u64>,
+    pub similarities: Array2D<u64>,
+}
+
+impl PairwiseScores {
+    /// Writes one line per pair of columns, with the two column numbers followed by their scores.
+    pub fn write_csv(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "first_column,second_column,total_distance,similarity_score"
+        )?;
+        for (first, second) in self.distances.indices_row_major() {
+            let distance = self.distances[(first, second)];
+            let similarity = self.similarities[(first, second)];
+            writeln!(out, "{first},{second},{distance},{similarity}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn calculate_pairwise_scores(columns: &[Vec<u64>]) -> PairwiseScores {
+    // Sort and count every column once, instead of once per pair it is in
+    let sorted = columns
+        .iter()
+        .map(|column| {
+            let mut column = column.clone();
+            column.sort_unstable();
+            column
+        })
+        .collect::<Vec<_>>();
+    let counts = columns
+        .iter()
+        .map(|column| {
+            column.iter().fold(HashMap::new(), |mut acc, &location| {
+                *acc.entry(location).or_insert(0u64) += 1;
+                acc
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let n = columns.len();
+    let pairs = || (0..n).flat_map(move |i| (0..n).map(move |j| (i, j)));
+
+    let distances = pairs()
+        .map(|(i, j)| {
+            sorted[i]
+                .iter()
+                .zip(&sorted[j])
+                .map(|(x, y)| x.abs_diff(*y))
+                .sum()
+        })
+        .collect::<Vec<u64>>();
+    let similarities = pairs()
+        .map(|(i, j)| {
+            counts[i]
+                .iter()
+                .map(|(location, count)| location * count * counts[j].get(location).unwrap_or(&0))
+                .sum()
+        })
+        .collect::<Vec<u64>>();
+
+    PairwiseScores {
+        distances: Array2D::from_row_major(&distances, n, n).expect("There is one score per pair"),
+        similarities: Array2D::from_row_major(&similarities, n, n)
+            .expect("There is one score per pair"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::{
+        calculate_similarity_score, calculate_total_distance, parse_columns, EXAMPLE,
+    };
+
+    #[test]
+    fn matches_scores_of_each_pair() {
+        // The example with a third column added
+        let input = EXAMPLE
+            .lines()
+            .zip([1, 3, 3, 7, 2, 3])
+            .map(|(line, location)| format!("{line}   {location}\n"))
+            .collect::<String>();
+        let columns = parse_columns(&input).unwrap();
+        let scores = calculate_pairwise_scores(&columns);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let distance = calculate_total_distance(&columns[i], &columns[j]);
+                let similarity = calculate_similarity_score(&columns[i], &columns[j]);
+                assert_eq!(scores.distances[(i, j)], distance);
+                assert_eq!(scores.similarities[(i, j)], similarity);
+            }
+        }
+        assert_eq!(scores.distances[(0, 1)], 11);
+        assert_eq!(scores.similarities[(0, 1)], 31);
+    }
+
+    #[test]
+    fn csv_has_a_line_per_pair() {
+        let columns = parse_columns("1 2\n3 4\n").unwrap();
+        let mut csv = Vec::new();
+        calculate_pairwise_scores(&columns)
+            .write_csv(&mut csv)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\
+first_column,second_column,total_distance,similarity_score
+0,0,0,4
+0,1,2,0
+1,0,2,0
+1,1,0,6
+"
+        );
+    }
+}
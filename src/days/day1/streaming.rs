@@ -0,0 +1,195 @@
+//! Day 1 for location lists too large to keep in memory. The total distance is computed with an
+//! external sort: each column is sorted in chunks that are spilled to temporary files, which are
+//! then merged back together. The similarity score only needs a count of each distinct location
+//! of the second column.
+
+use crate::{parse::Pattern, InputError, InputErrorKind};
+use anyhow::Context;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, Write},
+};
+
+/// Number of locations per column sorted in memory at a time, about 80 MB each.
+pub const DEFAULT_CHUNK_LEN: usize = 10_000_000;
+
+/// Sorted runs of a single column, each in its own temporary file.
+struct SortedRuns {
+    chunk: Vec<u64>,
+    chunk_len: usize,
+    runs: Vec<File>,
+}
+
+impl SortedRuns {
+    fn new(chunk_len: usize) -> Self {
+        Self {
+            chunk: Vec::new(),
+            chunk_len: chunk_len.max(1),
+            runs: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, location: u64) -> io::Result<()> {
+        self.chunk.push(location);
+        if self.chunk.len() >= self.chunk_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.chunk.sort_unstable();
+
+        let mut out = BufWriter::new(tempfile::tempfile()?);
+        for location in self.chunk.drain(..) {
+            out.write_all(&location.to_le_bytes())?;
+        }
+        let mut file = out.into_inner().map_err(io::IntoInnerError::into_error)?;
+        file.rewind()?;
+
+        self.runs.push(file);
+        Ok(())
+    }
+
+    /// Every location pushed so far, in ascending order.
+    fn into_sorted(mut self) -> io::Result<MergedRuns> {
+        if !self.chunk.is_empty() {
+            self.spill()?;
+        }
+        MergedRuns::new(self.runs)
+    }
+}
+
+/// A k-way merge of sorted runs, reading each one sequentially.
+struct MergedRuns {
+    readers: Vec<BufReader<File>>,
+    heads: BinaryHeap<Reverse<(u64, usize)>>,
+}
+
+impl MergedRuns {
+    fn new(runs: Vec<File>) -> io::Result<Self> {
+        let mut merged = Self {
+            readers: runs.into_iter().map(BufReader::new).collect(),
+            heads: BinaryHeap::new(),
+        };
+        for run in 0..merged.readers.len() {
+            merged.advance(run)?;
+        }
+        Ok(merged)
+    }
+
+    fn advance(&mut self, run: usize) -> io::Result<()> {
+        let mut bytes = [0; 8];
+        match self.readers[run].read_exact(&mut bytes) {
+            Ok(()) => {
+                self.heads.push(Reverse((u64::from_le_bytes(bytes), run)));
+                Ok(())
+            }
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Iterator for MergedRuns {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((location, run)) = self.heads.pop()?;
+        Some(self.advance(run).map(|()| location))
+    }
+}
+
+/// The total distance and similarity score of the location lists in `input`, holding at most
+/// `chunk_len` locations per column in memory besides the count of each distinct location of the
+/// second column.
+pub fn solve(input: impl BufRead, chunk_len: usize) -> anyhow::Result<(u64, u64)> {
+    // Same fields as `parse_columns`, so both modes accept the same files
+    let pattern = Pattern::new("{}");
+    let mut num_columns = None;
+    let mut first_column = SortedRuns::new(chunk_len);
+    let mut second_column = SortedRuns::new(chunk_len);
+    let mut second_counts = HashMap::<u64, u64>::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.context("Failed to read input")?;
+        let (row,) = pattern.parse::<(Vec<u64>,)>(&line).map_err(|e| {
+            let column = e.column;
+            e.at(i + 1, column)
+        })?;
+
+        let expected = *num_columns.get_or_insert(row.len());
+        if row.len() != expected {
+            let kind = InputErrorKind::RaggedRow {
+                expected,
+                found: row.len(),
+            };
+            return Err(InputError::new(kind).at(i + 1, None).into());
+        }
+        anyhow::ensure!(
+            expected >= 2,
+            "Input should have at least 2 columns of locations, found {expected}"
+        );
+        let (first, second) = (row[0], row[1]);
+
+        first_column.push(first)?;
+        second_column.push(second)?;
+        *second_counts.entry(second).or_default() += 1;
+    }
+
+    anyhow::ensure!(
+        num_columns.is_some(),
+        "Input should have at least 2 columns of locations, found 0"
+    );
+
+    let mut total_distance = 0;
+    let mut similarity_score = 0;
+    let mut second_sorted = second_column.into_sorted()?;
+    for first in first_column.into_sorted()? {
+        let second = second_sorted
+            .next()
+            .expect("Both columns should have a location on every line")?;
+        let first = first?;
+        total_distance += first.abs_diff(second);
+        similarity_score += first * second_counts.get(&first).unwrap_or(&0);
+    }
+
+    Ok((total_distance, similarity_score))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        days::{
+            day1::{Day1, EXAMPLE},
+            Solution,
+        },
+        generate::Generate,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn example_with_several_runs_per_column() {
+        assert_eq!(solve(EXAMPLE.as_bytes(), 4).unwrap(), (11, 31));
+    }
+
+    #[test]
+    fn accepts_the_same_lines_as_parse_columns() {
+        assert_eq!(solve("1\t2\n3\t4\n".as_bytes(), 4).unwrap(), (2, 0));
+        assert_eq!(solve("3 4 1\n4 3 3\n".as_bytes(), 4).unwrap(), (0, 7));
+        assert!(solve("1 2\n3\n".as_bytes(), 4).is_err());
+        assert!(solve("1\n3\n".as_bytes(), 4).is_err());
+    }
+
+    #[test]
+    fn matches_in_memory_solution() {
+        let input = Day1::generate(&mut ChaCha8Rng::seed_from_u64(1), 1000);
+        let parsed = Day1::parse(&input).unwrap();
+        let expected = (Day1::part1(&parsed), Day1::part2(&parsed));
+        assert_eq!(solve(input.as_bytes(), 64).unwrap(), expected);
+    }
+}
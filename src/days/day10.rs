@@ -0,0 +1,201 @@
+use crate::{
+    days::{Part, Solution, Visualize},
+    generate::{grid_to_input, Generate},
+    render::{Color, Overlay},
+    search::{bfs, count_paths, count_paths_with_order},
+    try_parse_2d_number_grid, Coords2D, GetNeighbors, Grid, Point2D, Vec2D,
+};
+use array2d::Array2D;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+fn uphill_neighbors(grid: &Grid<u8>, pos: Coords2D) -> impl Iterator<Item = Coords2D> + '_ {
+    grid.get_neighboring_indices_no_diagonal(pos)
+        .into_iter()
+        .filter(move |next| grid[*next] == grid[pos] + 1)
+}
+
+fn find_trailhead_score(grid: &Grid<u8>, pos: Coords2D) -> usize {
+    if grid.get(pos).is_none_or(|v| *v != 0) {
+        return 0;
+    }
+
+    bfs(pos, |&p| uphill_neighbors(grid, p))
+        .reached()
+        .filter(|&&p| grid[p] == 9)
+        .count()
+}
+
+fn find_trailheads(grid: &Grid<u8>) -> impl Iterator<Item = Coords2D> + use<'_> {
+    grid.find_all(&0)
+}
+
+pub fn find_sum_of_trailhead_scores(grid: &Grid<u8>) -> usize {
+    find_trailheads(grid)
+        .map(|pos| find_trailhead_score(grid, pos))
+        .sum()
+}
+
+pub fn find_sum_of_trailhead_ratings(grid: &Grid<u8>) -> usize {
+    let trails_to_peak = count_paths(
+        find_trailheads(grid),
+        |&p| grid[p] == 9,
+        |&p| uphill_neighbors(grid, p),
+    )
+    .expect("Trails only go uphill, so they cannot form a cycle");
+
+    find_trailheads(grid).map(|pos| trails_to_peak[&pos]).sum()
+}
+
+/// Every cell lying on a hiking trail, ordered so that each one comes after the cells leading up
+/// to it.
+fn find_trail_cells(grid: &Grid<u8>) -> Vec<Coords2D> {
+    let (order, trails_to_peak) = count_paths_with_order(
+        find_trailheads(grid),
+        |&p| grid[p] == 9,
+        |&p| uphill_neighbors(grid, p),
+    )
+    .expect("Trails only go uphill, so they cannot form a cycle");
+
+    order
+        .into_iter()
+        .filter(|p| trails_to_peak[p] > 0)
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Grid::new(try_parse_2d_number_grid(input)?))
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        find_sum_of_trailhead_scores(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        find_sum_of_trailhead_ratings(grid)
+    }
+}
+
+impl Visualize for Day10 {
+    type Cell = u8;
+
+    fn cells(grid: &Self::Input) -> &Array2D<u8> {
+        grid.as_array()
+    }
+
+    fn palette(height: &u8) -> [u8; 3] {
+        let shade = 60 + 20 * height;
+        [shade, shade, shade]
+    }
+
+    fn overlays(grid: &Self::Input, part: Part) -> Vec<Overlay> {
+        let trails = find_trail_cells(grid);
+        let (ends, color) = match part {
+            Part::One => (9, Color::Red),
+            Part::Two => (0, Color::Green),
+        };
+        let highlighted_ends = trails
+            .iter()
+            .copied()
+            .filter(|&p| grid[p] == ends)
+            .collect::<Vec<_>>();
+
+        vec![
+            Overlay::new(trails, Color::Yellow),
+            Overlay::new(highlighted_ends, color),
+        ]
+    }
+}
+
+impl Generate for Day10 {
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        // Hills with a peak of 9 sloping down by one per step, so that there are plenty of trails,
+        // with some noise on top to break some of them
+        let mut heights = Grid::new(Array2D::filled_with(0u8, size, size));
+        for _ in 0..size * size / 60 {
+            let peak = Point2D::new(
+                rng.random_range(0..size) as isize,
+                rng.random_range(0..size) as isize,
+            );
+            for row in -9..=9isize {
+                for column in (row.abs() - 9)..=(9 - row.abs()) {
+                    let distance = (row.abs() + column.abs()) as u8;
+                    if let Some(pos) = heights.point_to_coords(peak + Vec2D::new(row, column)) {
+                        heights[pos] = heights[pos].max(9 - distance);
+                    }
+                }
+            }
+        }
+
+        for row in 0..size {
+            for column in 0..size {
+                if rng.random_bool(0.1) {
+                    heights[Coords2D::new(row, column)] = rng.random_range(0..=9);
+                }
+            }
+        }
+
+        grid_to_input(&heights.map(|&height| char::from(b'0' + height)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn trailhead_scores() {
+        let grid = Day10::parse(EXAMPLE).unwrap();
+        let scores = find_trailheads(&grid)
+            .map(|pos| find_trailhead_score(&grid, pos))
+            .collect::<Vec<_>>();
+        assert_eq!(scores, [5, 6, 5, 3, 1, 3, 5, 3, 5]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10::part1(&Day10::parse(EXAMPLE).unwrap()), 36);
+    }
+
+    #[test]
+    fn trailhead_ratings() {
+        let grid = Day10::parse(EXAMPLE).unwrap();
+        let trails_to_peak = count_paths(
+            find_trailheads(&grid),
+            |&p| grid[p] == 9,
+            |&p| uphill_neighbors(&grid, p),
+        )
+        .unwrap();
+        let ratings = find_trailheads(&grid)
+            .map(|pos| trails_to_peak[&pos])
+            .collect::<Vec<_>>();
+        assert_eq!(ratings, [20, 24, 10, 4, 1, 4, 5, 8, 5]);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10::part2(&Day10::parse(EXAMPLE).unwrap()), 81);
+    }
+}
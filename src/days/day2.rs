@@ -0,0 +1,275 @@
+use crate::{days::Solution, generate::Generate, parse::parse_lines};
+use clap::{Args, ValueEnum};
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::cmp::Ordering;
+
+/// Which way the levels of a report are allowed to go.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Direction {
+    Ascending,
+    Descending,
+    /// Either way, as long as every level goes the same way as the first change.
+    Either,
+}
+
+/// The rules a report has to follow to be safe. The default is the puzzle's: levels all
+/// increasing or all decreasing, by 1 to 3 at a time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Args)]
+pub struct SafetyPolicy {
+    /// Smallest allowed change between two adjacent levels that differ
+    #[arg(long, default_value_t = 1)]
+    pub min_step: u32,
+    /// Largest allowed change between two adjacent levels
+    #[arg(long, default_value_t = 3)]
+    pub max_step: u32,
+    /// Which way the levels of a safe report go
+    #[arg(long, value_enum, default_value_t = Direction::Either)]
+    pub direction: Direction,
+    /// Allow adjacent levels to be equal, without counting as a change of direction
+    #[arg(long)]
+    pub allow_equal: bool,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            allow_equal: false,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// Checks that the policy can be met at all, as no step fits between a larger minimum and a
+    /// smaller maximum.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.min_step <= self.max_step,
+            "The minimum step ({}) should not be larger than the maximum step ({})",
+            self.min_step,
+            self.max_step
+        );
+        Ok(())
+    }
+
+    pub fn allows(&self, levels: &[i32]) -> bool {
+        let mut trend = match self.direction {
+            Direction::Ascending => Some(Ordering::Greater),
+            Direction::Descending => Some(Ordering::Less),
+            Direction::Either => None,
+        };
+
+        levels.iter().tuple_windows().all(|(&prev, &current)| {
+            match current.cmp(&prev) {
+                Ordering::Equal => self.allow_equal,
+                ordering => {
+                    // Without a required direction, the first change decides it
+                    let trend = *trend.get_or_insert(ordering);
+                    let step = current.abs_diff(prev);
+                    ordering == trend && (self.min_step..=self.max_step).contains(&step)
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl Report {
+    pub fn new(levels: Vec<i32>) -> Self {
+        Self { levels }
+    }
+
+    fn with_level_removed(&self, idx: usize) -> Self {
+        let new_levels = self
+            .levels
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(i, _)| *i != idx)
+            .map(|(_, l)| l)
+            .collect();
+
+        Self { levels: new_levels }
+    }
+
+    pub fn is_safe(&self, policy: &SafetyPolicy) -> bool {
+        policy.allows(&self.levels)
+    }
+
+    pub fn is_safe_with_dampener(&self, policy: &SafetyPolicy) -> bool {
+        (0..self.levels.len())
+            .map(|i| self.with_level_removed(i))
+            .any(|report| report.is_safe(policy))
+    }
+}
+
+pub fn calculate_num_of_safe_reports(reports: &[Report], policy: &SafetyPolicy) -> usize {
+    reports
+        .iter()
+        .filter(|report| report.is_safe(policy))
+        .count()
+}
+
+pub fn calculate_num_of_safe_reports_with_dampener(
+    reports: &[Report],
+    policy: &SafetyPolicy,
+) -> usize {
+    reports
+        .iter()
+        .filter(|report| report.is_safe_with_dampener(policy))
+        .count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Report>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_lines::<(Vec<i32>,)>(input, "{}")?
+            .into_iter()
+            .map(|(levels,)| Report::new(levels))
+            .collect())
+    }
+
+    fn part1(reports: &Self::Input) -> Self::Answer1 {
+        calculate_num_of_safe_reports(reports, &SafetyPolicy::default())
+    }
+
+    fn part2(reports: &Self::Input) -> Self::Answer2 {
+        calculate_num_of_safe_reports_with_dampener(reports, &SafetyPolicy::default())
+    }
+}
+
+impl Generate for Day2 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let len = rng.random_range(5..=8);
+            let ascending = rng.random_bool(0.5);
+            let mut level = rng.random_range(30..70);
+            let mut levels = vec![level];
+            for _ in 1..len {
+                // Mostly safe steps, with the odd jump, plateau or turn to make reports unsafe
+                let step = match rng.random_range(0..10) {
+                    0 => rng.random_range(4..=6),
+                    1 => 0,
+                    2 => -rng.random_range(1..=3),
+                    _ => rng.random_range(1..=3),
+                };
+                level += if ascending { step } else { -step };
+                levels.push(level);
+            }
+            input += &levels.iter().join(" ");
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn safety_of_example_reports() {
+        let reports = Day2::parse(EXAMPLE).unwrap();
+        let policy = SafetyPolicy::default();
+        let safe = reports
+            .iter()
+            .map(|report| report.is_safe(&policy))
+            .collect::<Vec<_>>();
+        assert_eq!(safe, [true, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day2::part1(&Day2::parse(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn safety_of_example_reports_with_dampener() {
+        let reports = Day2::parse(EXAMPLE).unwrap();
+        let policy = SafetyPolicy::default();
+        let safe = reports
+            .iter()
+            .map(|report| report.is_safe_with_dampener(&policy))
+            .collect::<Vec<_>>();
+        assert_eq!(safe, [true, false, false, true, true, true]);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day2::part2(&Day2::parse(EXAMPLE).unwrap()), 4);
+    }
+
+    #[test]
+    fn safety_under_other_policies() {
+        let reports = Day2::parse(EXAMPLE).unwrap();
+        let safe = |policy: SafetyPolicy| {
+            reports
+                .iter()
+                .map(|report| report.is_safe(&policy))
+                .collect::<Vec<_>>()
+        };
+
+        let ascending = SafetyPolicy {
+            direction: Direction::Ascending,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(safe(ascending), [false, false, false, false, false, true]);
+
+        let larger_steps = SafetyPolicy {
+            max_step: 5,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(safe(larger_steps), [true, true, true, false, false, true]);
+
+        let equal_allowed = SafetyPolicy {
+            allow_equal: true,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(safe(equal_allowed), [true, false, false, false, true, true]);
+    }
+
+    #[test]
+    fn rejects_policies_without_valid_steps() {
+        assert!(SafetyPolicy::default().validate().is_ok());
+        let exact = SafetyPolicy {
+            min_step: 2,
+            max_step: 2,
+            ..SafetyPolicy::default()
+        };
+        assert!(exact.validate().is_ok());
+        let impossible = SafetyPolicy {
+            min_step: 4,
+            max_step: 3,
+            ..SafetyPolicy::default()
+        };
+        assert!(impossible.validate().is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{days::Solution, generate::Generate};
+use rand::{seq::IndexedRandom, Rng};
+use rand_chacha::ChaCha8Rng;
+use regex::Regex;
+
+pub fn calculate_mul_sum(input: &str) -> u64 {
+    let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+
+    re.captures_iter(input)
+        .map(|instruction| {
+            let num1 = instruction.get(1).unwrap().as_str().parse::<u64>().unwrap();
+            let num2 = instruction.get(2).unwrap().as_str().parse::<u64>().unwrap();
+            (num1, num2)
+        })
+        .map(|(lhs, rhs)| lhs * rhs)
+        .sum::<u64>()
+}
+
+pub fn calculate_mul_sum_with_conditionals(input: &str) -> u64 {
+    let re = Regex::new(r"^mul\((\d+),(\d+)\)").unwrap();
+    let mut enabled = true;
+    let mut sum = 0;
+
+    let mut ptr = input;
+
+    while !ptr.is_empty() {
+        if let Some(instruction) = re.captures(ptr) {
+            let match_len = instruction.get(0).unwrap().len();
+            let lhs = instruction.get(1).unwrap().as_str().parse::<u64>().unwrap();
+            let rhs = instruction.get(2).unwrap().as_str().parse::<u64>().unwrap();
+
+            if enabled {
+                sum += lhs * rhs;
+            }
+
+            ptr = &ptr[match_len..];
+        } else if ptr.starts_with("do()") {
+            enabled = true;
+            ptr = &ptr[4..];
+        } else if ptr.starts_with("don't") {
+            enabled = false;
+            ptr = &ptr[5..];
+        } else {
+            ptr = &ptr[1..];
+        }
+    }
+
+    sum
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        calculate_mul_sum(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        calculate_mul_sum_with_conditionals(input)
+    }
+}
+
+const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,;:'?+-_ whatmul()don";
+
+impl Generate for Day3 {
+    const DEFAULT_SIZE: usize = 6;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..120 {
+                let lhs = rng.random_range(1..1000);
+                let rhs = rng.random_range(1..1000);
+                let instruction = match rng.random_range(0..10) {
+                    0 => "do()".to_string(),
+                    1 => "don't()".to_string(),
+                    2 => format!("mul({lhs},{rhs}]"),
+                    3 => format!("mul({lhs} ,{rhs})"),
+                    4 => format!("mul[{lhs},{rhs})"),
+                    _ => format!("mul({lhs},{rhs})"),
+                };
+                input += &instruction;
+                for _ in 0..rng.random_range(0..12) {
+                    input.push(*JUNK.choose(rng).expect("Junk should not be empty") as char);
+                }
+            }
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EXAMPLE_2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn only_well_formed_instructions_count() {
+        assert_eq!(calculate_mul_sum("mul(44,46)"), 2024);
+        assert_eq!(calculate_mul_sum("mul(4*"), 0);
+        assert_eq!(calculate_mul_sum("mul(6,9!"), 0);
+        assert_eq!(calculate_mul_sum("?(12,34)"), 0);
+        assert_eq!(calculate_mul_sum("mul ( 2 , 4 )"), 0);
+    }
+
+    #[test]
+    fn part1_example() {
+        // 2*4 + 5*5 + 11*8 + 8*5
+        assert_eq!(Day3::part1(&Day3::parse(EXAMPLE_1).unwrap()), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        // 2*4 + 8*5, as mul(5,5) and mul(11,8) are disabled
+        assert_eq!(Day3::part2(&Day3::parse(EXAMPLE_2).unwrap()), 48);
+    }
+}
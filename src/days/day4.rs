@@ -0,0 +1,253 @@
+use crate::{
+    days::{Part, Solution, Visualize},
+    generate::{grid_to_input, random_grid, Generate},
+    render::{Color, Overlay},
+    Coords2D, Direction8, Grid,
+};
+use array2d::Array2D;
+use phf::phf_map;
+use rand::{seq::IndexedRandom, Rng};
+use rand_chacha::ChaCha8Rng;
+
+const NEXT_MAP: phf::Map<char, char> = phf_map! {
+    'X' => 'M',
+    'M' => 'A',
+    'A' => 'S'
+};
+
+fn step_in_direction(chars: &Grid<char>, pos: Coords2D, direction: Direction8) -> Option<Coords2D> {
+    chars.checked_step(pos, direction.offset())
+}
+
+fn search_word_in_direction_from(
+    chars: &Grid<char>,
+    current_pos: Coords2D,
+    direction: Direction8,
+    char_to_check: char,
+) -> bool {
+    let Some(current_char) = chars.get(current_pos) else {
+        return false;
+    };
+
+    if *current_char != char_to_check {
+        return false;
+    }
+
+    // We've reached the end of the word
+    if char_to_check == 'S' {
+        return true;
+    }
+
+    let Some(next) = step_in_direction(chars, current_pos, direction) else {
+        return false;
+    };
+    let next_char = NEXT_MAP.get(&char_to_check).unwrap();
+    search_word_in_direction_from(chars, next, direction, *next_char)
+}
+
+pub fn find_num_of_xmas_in_grid(chars: &Grid<char>) -> usize {
+    chars
+        .find_all(&'X')
+        .map(|pos| {
+            Direction8::iter()
+                .filter(|d| search_word_in_direction_from(chars, pos, *d, 'X'))
+                .count()
+        })
+        .sum()
+}
+
+fn find_xmas_cells(chars: &Grid<char>) -> Vec<Coords2D> {
+    chars
+        .find_all(&'X')
+        .flat_map(|pos| {
+            Direction8::iter()
+                .filter(move |d| search_word_in_direction_from(chars, pos, *d, 'X'))
+                .flat_map(move |d| {
+                    std::iter::successors(Some(pos), move |&p| step_in_direction(chars, p, d))
+                        .take(4)
+                })
+        })
+        .collect()
+}
+
+fn has_cross_mas(chars: &Grid<char>, pos: Coords2D) -> bool {
+    let Some(current_char) = chars.get(pos) else {
+        return false;
+    };
+
+    if *current_char != 'A' {
+        return false;
+    };
+
+    let Some(&up_right) =
+        step_in_direction(chars, pos, Direction8::UpRight).and_then(|p| chars.get(p))
+    else {
+        return false;
+    };
+
+    let Some(&down_right) =
+        step_in_direction(chars, pos, Direction8::DownRight).and_then(|p| chars.get(p))
+    else {
+        return false;
+    };
+
+    let Some(&down_left) =
+        step_in_direction(chars, pos, Direction8::DownLeft).and_then(|p| chars.get(p))
+    else {
+        return false;
+    };
+
+    let Some(&up_left) =
+        step_in_direction(chars, pos, Direction8::UpLeft).and_then(|p| chars.get(p))
+    else {
+        return false;
+    };
+
+    let has_first_arm = up_right == 'M' && down_left == 'S' || up_right == 'S' && down_left == 'M';
+    let has_second_arm = up_left == 'M' && down_right == 'S' || up_left == 'S' && down_right == 'M';
+
+    has_first_arm && has_second_arm
+}
+
+pub fn find_num_of_cross_mas_in_grid(chars: &Grid<char>) -> usize {
+    chars
+        .find_all(&'A')
+        .filter(|pos| has_cross_mas(chars, *pos))
+        .count()
+}
+
+fn find_cross_mas_cells(chars: &Grid<char>) -> Vec<Coords2D> {
+    chars
+        .find_all(&'A')
+        .filter(|pos| has_cross_mas(chars, *pos))
+        .flat_map(|pos| {
+            let corners = [
+                Direction8::UpLeft,
+                Direction8::UpRight,
+                Direction8::DownLeft,
+                Direction8::DownRight,
+            ]
+            .into_iter()
+            .filter_map(move |d| step_in_direction(chars, pos, d));
+            std::iter::once(pos).chain(corners)
+        })
+        .collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(chars: &Self::Input) -> Self::Answer1 {
+        find_num_of_xmas_in_grid(chars)
+    }
+
+    fn part2(chars: &Self::Input) -> Self::Answer2 {
+        find_num_of_cross_mas_in_grid(chars)
+    }
+}
+
+impl Visualize for Day4 {
+    type Cell = char;
+
+    fn cells(chars: &Self::Input) -> &Array2D<char> {
+        chars.as_array()
+    }
+
+    fn palette(_: &char) -> [u8; 3] {
+        [235, 235, 235]
+    }
+
+    fn overlays(chars: &Self::Input, part: Part) -> Vec<Overlay> {
+        let hits = match part {
+            Part::One => find_xmas_cells(chars),
+            Part::Two => find_cross_mas_cells(chars),
+        };
+        vec![Overlay::new(hits, Color::Green)]
+    }
+}
+
+impl Generate for Day4 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        let mut chars = random_grid(rng, size, |rng| {
+            *b"XMAS".choose(rng).expect("Letters should not be empty") as char
+        });
+
+        // Random letters rarely spell anything, so write some words in on top
+        for _ in 0..size * size / 20 {
+            let direction = *Direction8::ALL.choose(rng).expect("There are 8 directions");
+            let mut pos = Coords2D::new(rng.random_range(0..size), rng.random_range(0..size));
+            for letter in "XMAS".chars() {
+                chars[pos] = letter;
+                match step_in_direction(&chars, pos, direction) {
+                    Some(next) => pos = next,
+                    None => break,
+                }
+            }
+        }
+
+        grid_to_input(&chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    const SMALL_EXAMPLE: &str = "\
+..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....
+";
+
+    #[test]
+    fn small_example_words() {
+        let chars = Day4::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(find_num_of_xmas_in_grid(&chars), 4);
+        assert_eq!(find_xmas_cells(&chars).len(), 4 * 4);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day4::part1(&Day4::parse(EXAMPLE).unwrap()), 18);
+    }
+
+    #[test]
+    fn cross_mas_is_centered_on_a() {
+        let chars = Day4::parse("M.S\n.A.\nM.S\n").unwrap();
+        assert!(has_cross_mas(&chars, Coords2D::new(1, 1)));
+        assert!(!has_cross_mas(&chars, Coords2D::new(0, 0)));
+        assert_eq!(find_cross_mas_cells(&chars).len(), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day4::part2(&Day4::parse(EXAMPLE).unwrap()), 9);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    days::Solution, generate::Generate, parse::parse_lines_from, split_sections_with_lines,
+};
+use anyhow::Context;
+use itertools::Itertools;
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng,
+};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+pub fn is_valid_update(update: &[u32], rules: &HashSet<(u32, u32)>) -> bool {
+    for (i, &n) in update.iter().enumerate() {
+        for &m in &update[..i] {
+            if rules.contains(&(n, m)) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn middle_of_vec<T: Copy>(v: &[T]) -> T {
+    v[v.len() / 2]
+}
+
+pub fn calculate_sum_of_middle_values_of_valid_updates(
+    updates: &[Vec<u32>],
+    rules: &HashSet<(u32, u32)>,
+) -> u32 {
+    updates
+        .iter()
+        .filter(|u| is_valid_update(u, rules))
+        .map(|u| middle_of_vec(u))
+        .sum::<u32>()
+}
+
+fn check_validity_at(
+    update: &[u32],
+    at: usize,
+    rules: &HashSet<(u32, u32)>,
+) -> Option<(usize, usize)> {
+    let n = update[at];
+    for (i, &m) in update[..at].iter().enumerate() {
+        if rules.contains(&(n, m)) {
+            return Some((i, at));
+        }
+    }
+
+    None
+}
+
+pub fn fix_invalid_update(update: &[u32], rules: &HashSet<(u32, u32)>) -> Vec<u32> {
+    let mut fixed = update.to_vec();
+    let mut i = 0;
+    loop {
+        if i == update.len() {
+            break;
+        }
+        if let Some(to_swap) = check_validity_at(&fixed, i, rules) {
+            fixed.swap(to_swap.0, to_swap.1);
+            // Need to go back and re-check from the position we placed the later value to,
+            // as we could've broken the array in some other way
+            i = to_swap.0;
+        } else {
+            i += 1;
+        }
+    }
+
+    fixed
+}
+
+pub fn calculate_sum_of_middle_values_of_fixed_updates(
+    updates: &[Vec<u32>],
+    rules: &HashSet<(u32, u32)>,
+) -> u32 {
+    updates
+        .iter()
+        .filter(|u| !is_valid_update(u, rules))
+        .map(|u| fix_invalid_update(u, rules))
+        .map(|u| middle_of_vec(&u))
+        .sum::<u32>()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (HashSet<(u32, u32)>, Vec<Vec<u32>>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let [(rules_line, rules), (updates_line, updates)]: [(usize, &str); 2] =
+            split_sections_with_lines(input).try_into().map_err(|sections: Vec<_>| {
+                anyhow::anyhow!(
+                    "input should have rules and updates separated by a blank line, found {} sections",
+                    sections.len()
+                )
+            })?;
+
+        let rules = parse_lines_from::<(u32, u32)>(rules, "{}|{}", rules_line)
+            .context("Failed to parse the page ordering rules")?
+            .into_iter()
+            .collect();
+
+        let updates = parse_lines_from::<(Vec<u32>,)>(updates, "{}", updates_line)
+            .context("Failed to parse the updates")?
+            .into_iter()
+            .map(|(pages,)| pages)
+            .collect();
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Self::Answer1 {
+        calculate_sum_of_middle_values_of_valid_updates(updates, rules)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Self::Answer2 {
+        calculate_sum_of_middle_values_of_fixed_updates(updates, rules)
+    }
+}
+
+impl Generate for Day5 {
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        // Like the real input, there is a rule for every pair of pages, all consistent with
+        // a single order of the pages
+        let mut pages = (10..100).collect::<Vec<u32>>();
+        pages.shuffle(rng);
+        pages.truncate(49);
+
+        let mut input = String::new();
+        for (i, first) in pages.iter().enumerate() {
+            for second in &pages[i + 1..] {
+                input += &format!("{first}|{second}\n");
+            }
+        }
+        input.push('\n');
+
+        for _ in 0..size {
+            let len = 2 * rng.random_range(2..12) + 1;
+            let mut update = pages.choose_multiple(rng, len).copied().collect::<Vec<_>>();
+            if rng.random_bool(0.5) {
+                let order = |page: &u32| pages.iter().position(|p| p == page);
+                update.sort_by_key(order);
+            }
+            input += &update.iter().join(",");
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputError;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn validity_of_example_updates() {
+        let (rules, updates) = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(rules.len(), 21);
+        let valid = updates
+            .iter()
+            .map(|update| is_valid_update(update, &rules))
+            .collect::<Vec<_>>();
+        assert_eq!(valid, [true, true, true, false, false, false]);
+    }
+
+    #[test]
+    fn part1_example() {
+        // 61 + 53 + 29
+        assert_eq!(Day5::part1(&Day5::parse(EXAMPLE).unwrap()), 143);
+    }
+
+    #[test]
+    fn fixes_invalid_example_updates() {
+        let (rules, updates) = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            fix_invalid_update(&updates[3], &rules),
+            [97, 75, 47, 61, 53]
+        );
+        assert_eq!(fix_invalid_update(&updates[4], &rules), [61, 29, 13]);
+        assert_eq!(
+            fix_invalid_update(&updates[5], &rules),
+            [97, 75, 47, 29, 13]
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        // 47 + 29 + 47
+        assert_eq!(Day5::part2(&Day5::parse(EXAMPLE).unwrap()), 123);
+    }
+
+    #[test]
+    fn errors_in_updates_count_lines_from_the_start_of_the_input() {
+        let error = Day5::parse("47|53\n97|13\n\n75,47,61\n75,x,61\n").unwrap_err();
+        let error = error.downcast_ref::<InputError>().unwrap();
+        assert_eq!((error.line, error.column), (Some(5), Some(4)));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{
+    days::{Part, Solution, Visualize},
+    generate::{grid_to_input, random_grid, Generate},
+    render::{Color, Overlay},
+    Coords2D, Direction4, Grid,
+};
+use anyhow::Context;
+use array2d::Array2D;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug)]
+pub enum Tile {
+    Empty,
+    Obstacle,
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::Empty => '.',
+            Self::Obstacle => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// The guard, drawn as an arrow pointing the way they face.
+const GUARD_CHARS: [char; 4] = ['^', '>', 'v', '<'];
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            c if GUARD_CHARS.contains(&c) => Ok(Self::Empty),
+            '#' => Ok(Self::Obstacle),
+            c => Err(c),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    starting_position: Coords2D,
+    starting_direction: Direction4,
+    guard_position: Coords2D,
+    direction: Direction4,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = anyhow::Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars = value.parse::<Grid<char>>()?;
+        let (guard_position, &guard) = chars
+            .iter()
+            .find(|(_, c)| GUARD_CHARS.contains(c))
+            .context("Guard character (^, >, v or <) not found")?;
+        let direction = Direction4::try_from(guard).expect("Guard characters are arrows");
+        let tiles = value.parse::<Grid<Tile>>()?;
+
+        Ok(Self {
+            tiles,
+            starting_position: guard_position,
+            starting_direction: direction,
+            guard_position,
+            direction,
+        })
+    }
+}
+
+impl Map {
+    fn step<F: FnMut(Coords2D, Direction4) -> bool>(&mut self, mut callback: F) -> bool {
+        let Some(next_pos) = self
+            .tiles
+            .checked_step(self.guard_position, self.direction.offset())
+        else {
+            return false;
+        };
+
+        let tile = self
+            .tiles
+            .get(next_pos)
+            .expect("Should be a valid index since we checked it above");
+        if let Tile::Obstacle = tile {
+            self.direction = self.direction.turn_right();
+            self.step(callback)
+        } else {
+            if callback(next_pos, self.direction) {
+                return false;
+            }
+            self.guard_position = next_pos;
+            true
+        }
+    }
+
+    fn simulate_and_get_unique_visited_tiles(&mut self) -> HashSet<Coords2D> {
+        let mut visited_tiles = HashSet::from([self.guard_position]);
+
+        while self.step(|coords2d: Coords2D, _| {
+            visited_tiles.insert(coords2d);
+            false
+        }) {}
+
+        visited_tiles
+    }
+
+    /// The tiles the guard visits, in the order they are first reached.
+    fn simulate_and_get_path(&mut self) -> Vec<Coords2D> {
+        let mut visited_tiles = HashSet::from([self.guard_position]);
+        let mut path = vec![self.guard_position];
+
+        while self.step(|coords2d: Coords2D, _| {
+            if visited_tiles.insert(coords2d) {
+                path.push(coords2d);
+            }
+            false
+        }) {}
+
+        path
+    }
+
+    /// How many times the guard steps onto each tile, with the starting tile counted once for
+    /// where they start. Turning on the spot is not a visit.
+    fn simulate_and_count_visits(&mut self) -> HashMap<Coords2D, usize> {
+        let mut visits = HashMap::from([(self.guard_position, 1)]);
+
+        while self.step(|coords2d: Coords2D, _| {
+            *visits.entry(coords2d).or_default() += 1;
+            false
+        }) {}
+
+        visits
+    }
+
+    pub fn count_number_of_unique_visited_tiles(&mut self) -> usize {
+        self.simulate_and_get_unique_visited_tiles().len()
+    }
+
+    fn with_additional_obstacle(&self, new_obstacle_pos: Coords2D) -> Self {
+        let mut new_tiles = self.tiles.clone();
+        new_tiles
+            .set(new_obstacle_pos, Tile::Obstacle)
+            .expect("Should be valid coords since we got it from `tiles");
+        Self {
+            starting_position: self.starting_position,
+            starting_direction: self.starting_direction,
+            guard_position: self.starting_position,
+            tiles: new_tiles,
+            direction: self.starting_direction,
+        }
+    }
+
+    fn simulate_and_check_if_cycle(&mut self) -> bool {
+        let mut unique_tiles_and_dirs = HashSet::from([(self.guard_position, self.direction)]);
+        let mut is_cycle = false;
+
+        while self.step(|coords, direction| {
+            let is_new_tile = unique_tiles_and_dirs.insert((coords, direction));
+            is_cycle = !is_new_tile;
+            is_cycle
+        }) {}
+
+        is_cycle
+    }
+
+    fn find_added_obstacles_that_result_in_cycle(&mut self) -> Vec<Coords2D> {
+        let path = self.simulate_and_get_path();
+        let mut obstacles = Vec::new();
+
+        for potential_obstacle_tile in path {
+            if potential_obstacle_tile == self.starting_position {
+                // Cannot place new obstacle on guard's starting position
+                continue;
+            }
+
+            let mut new_map = self.with_additional_obstacle(potential_obstacle_tile);
+            let does_result_in_cycle = new_map.simulate_and_check_if_cycle();
+            if does_result_in_cycle {
+                obstacles.push(potential_obstacle_tile);
+            }
+        }
+
+        obstacles
+    }
+
+    pub fn count_number_of_added_obstacles_that_result_in_cycle(&mut self) -> usize {
+        self.find_added_obstacles_that_result_in_cycle().len()
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Answer1 {
+        map.clone().count_number_of_unique_visited_tiles()
+    }
+
+    fn part2(map: &Self::Input) -> Self::Answer2 {
+        map.clone()
+            .count_number_of_added_obstacles_that_result_in_cycle()
+    }
+}
+
+impl Visualize for Day6 {
+    type Cell = Tile;
+
+    fn cells(map: &Self::Input) -> &Array2D<Tile> {
+        map.tiles.as_array()
+    }
+
+    fn palette(tile: &Tile) -> [u8; 3] {
+        match tile {
+            Tile::Empty => [235, 235, 235],
+            Tile::Obstacle => [60, 60, 60],
+        }
+    }
+
+    fn overlays(map: &Self::Input, part: Part) -> Vec<Overlay> {
+        let path = map.clone().simulate_and_get_path();
+        let path = Overlay::new(path, Color::Yellow).with_glyph('X');
+
+        match part {
+            Part::One => vec![path],
+            Part::Two => {
+                let obstacles = map.clone().find_added_obstacles_that_result_in_cycle();
+                vec![path, Overlay::new(obstacles, Color::Red).with_glyph('O')]
+            }
+        }
+    }
+
+    fn visit_counts(map: &Self::Input, _part: Part) -> Option<HashMap<Coords2D, usize>> {
+        Some(map.clone().simulate_and_count_visits())
+    }
+}
+
+impl Generate for Day6 {
+    const DEFAULT_SIZE: usize = 130;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        loop {
+            let mut tiles = random_grid(rng, size, |rng| match rng.random_bool(0.1) {
+                true => '#',
+                false => '.',
+            });
+            let guard = Coords2D::new(rng.random_range(0..size), rng.random_range(0..size));
+            tiles[guard] = '^';
+            if let Some(ahead) = tiles.checked_step(guard, Direction4::Up.offset()) {
+                // Make sure the guard can move at all
+                tiles[ahead] = '.';
+            }
+
+            // The puzzle needs the guard to leave the map eventually, so try again if they don't
+            let input = grid_to_input(&tiles);
+            let mut map = Map::try_from(input.as_str()).expect("Generated map should be valid");
+            if !map.simulate_and_check_if_cycle() {
+                return input;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn guard_starts_facing_up() {
+        let map = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(map.guard_position, Coords2D::new(6, 4));
+        assert_eq!(map.direction, Direction4::Up);
+    }
+
+    #[test]
+    fn guard_can_start_facing_any_way() {
+        for (guard, direction) in [
+            ('>', Direction4::Right),
+            ('v', Direction4::Down),
+            ('<', Direction4::Left),
+        ] {
+            let map = Day6::parse(&EXAMPLE.replace('^', &guard.to_string())).unwrap();
+            assert_eq!(map.guard_position, Coords2D::new(6, 4));
+            assert_eq!(map.direction, direction);
+        }
+
+        // Facing left, the guard first walks left until the obstacle at (6, 1)
+        let path = Day6::parse(&EXAMPLE.replace('^', "<"))
+            .unwrap()
+            .simulate_and_get_path();
+        assert_eq!(path[..3], [(6, 4), (6, 3), (6, 2)].map(Coords2D::from));
+    }
+
+    #[test]
+    fn guard_path_visits_41_tiles() {
+        let path = Day6::parse(EXAMPLE).unwrap().simulate_and_get_path();
+        assert_eq!(path.len(), 41);
+        assert_eq!(path[..3], [(6, 4), (5, 4), (4, 4)].map(Coords2D::from));
+        assert_eq!(path.last(), Some(&Coords2D::new(9, 7)));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day6::part1(&Day6::parse(EXAMPLE).unwrap()), 41);
+    }
+
+    #[test]
+    fn six_obstacle_positions_cause_a_cycle() {
+        let mut obstacles = Day6::parse(EXAMPLE)
+            .unwrap()
+            .find_added_obstacles_that_result_in_cycle();
+        obstacles.sort_by_key(|p| (p.row, p.column));
+        let expected = [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)].map(Coords2D::from);
+        assert_eq!(obstacles, expected);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day6::part2(&Day6::parse(EXAMPLE).unwrap()), 6);
+    }
+}
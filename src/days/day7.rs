@@ -0,0 +1,217 @@
+use crate::{days::Solution, generate::Generate, parse::parse_lines};
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+fn num_of_digits_of_base_10_number(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+fn can_calibration_be_made_true_with_addition_and_multiplication(
+    result: u64,
+    values: &[u64],
+) -> bool {
+    if values.is_empty() {
+        return result == 0;
+    }
+
+    fn check(result: u64, values: &[u64], current: u64, idx: usize) -> bool {
+        if idx == values.len() {
+            return current == result;
+        }
+        if current > result {
+            return false;
+        }
+        let n = values[idx];
+        let added = current + n;
+        let multiplied = current * n;
+
+        let with_addition = check(result, values, added, idx + 1);
+        let with_multiplication = check(result, values, multiplied, idx + 1);
+
+        with_addition || with_multiplication
+    }
+
+    check(result, values, values[0], 1)
+}
+
+fn can_calibration_be_made_true_with_addition_multiplication_and_concatenation(
+    result: u64,
+    values: &[u64],
+) -> bool {
+    if values.is_empty() {
+        return result == 0;
+    }
+
+    fn check(result: u64, values: &[u64], current: u64, idx: usize) -> bool {
+        if idx == values.len() {
+            return current == result;
+        }
+        if current > result {
+            return false;
+        }
+        let n = values[idx];
+        let added = current + n;
+        let multiplied = current * n;
+        let concatenated = {
+            // 12 || 345 = 12000 + 345 = 12 * (floor(log10(345)) + 1) + 345
+            let n_num_of_digits = num_of_digits_of_base_10_number(n);
+            let to_shift = 10u64.pow(n_num_of_digits);
+            current * to_shift + n
+        };
+
+        let with_addition = check(result, values, added, idx + 1);
+        let with_multiplication = check(result, values, multiplied, idx + 1);
+        let with_concatenation = check(result, values, concatenated, idx + 1);
+
+        with_addition || with_multiplication || with_concatenation
+    }
+
+    check(result, values, values[0], 1)
+}
+
+pub fn calculate_sum_of_calculations_made_true_with_addition_and_multiplication(
+    calculations: &[(u64, Vec<u64>)],
+) -> u64 {
+    calculations
+        .iter()
+        .filter(|(result, values)| {
+            can_calibration_be_made_true_with_addition_and_multiplication(*result, values)
+        })
+        .map(|(result, _)| result)
+        .sum()
+}
+
+pub fn calculate_sum_of_calculations_made_true_with_addition_multiplication_and_concatenation(
+    calculations: &[(u64, Vec<u64>)],
+) -> u64 {
+    calculations
+        .iter()
+        .filter(|(result, values)| {
+            can_calibration_be_made_true_with_addition_multiplication_and_concatenation(
+                *result, values,
+            )
+        })
+        .map(|(result, _)| result)
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_lines::<(u64, Vec<u64>)>(input, "{}: {}")?)
+    }
+
+    fn part1(calculations: &Self::Input) -> Self::Answer1 {
+        calculate_sum_of_calculations_made_true_with_addition_and_multiplication(calculations)
+    }
+
+    fn part2(calculations: &Self::Input) -> Self::Answer2 {
+        calculate_sum_of_calculations_made_true_with_addition_multiplication_and_concatenation(
+            calculations,
+        )
+    }
+}
+
+impl Generate for Day7 {
+    const DEFAULT_SIZE: usize = 850;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            // Every operator adds at most two digits, so results stay well within `u64`
+            let values = (0..rng.random_range(2..=8))
+                .map(|_| rng.random_range(1..100))
+                .collect::<Vec<u64>>();
+            let mut result = values[0];
+            for &n in &values[1..] {
+                result = match rng.random_range(0..3) {
+                    0 => result + n,
+                    1 => result * n,
+                    _ => result * 10u64.pow(num_of_digits_of_base_10_number(n)) + n,
+                };
+            }
+            if rng.random_bool(0.3) {
+                // Most likely unreachable now
+                result += 1;
+            }
+            input += &format!("{result}: {}\n", values.iter().join(" "));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn concatenation_appends_digits() {
+        assert_eq!(num_of_digits_of_base_10_number(0), 1);
+        assert_eq!(num_of_digits_of_base_10_number(345), 3);
+        assert!(
+            can_calibration_be_made_true_with_addition_multiplication_and_concatenation(
+                12345,
+                &[12, 345]
+            )
+        );
+    }
+
+    #[test]
+    fn example_calibrations_with_addition_and_multiplication() {
+        let true_results = Day7::parse(EXAMPLE)
+            .unwrap()
+            .into_iter()
+            .filter(|(result, values)| {
+                can_calibration_be_made_true_with_addition_and_multiplication(*result, values)
+            })
+            .map(|(result, _)| result)
+            .collect::<Vec<_>>();
+        assert_eq!(true_results, [190, 3267, 292]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day7::part1(&Day7::parse(EXAMPLE).unwrap()), 3749);
+    }
+
+    #[test]
+    fn example_calibrations_with_concatenation() {
+        let true_results = Day7::parse(EXAMPLE)
+            .unwrap()
+            .into_iter()
+            .filter(|(result, values)| {
+                can_calibration_be_made_true_with_addition_multiplication_and_concatenation(
+                    *result, values,
+                )
+            })
+            .map(|(result, _)| result)
+            .collect::<Vec<_>>();
+        assert_eq!(true_results, [190, 3267, 156, 7290, 192, 292]);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day7::part2(&Day7::parse(EXAMPLE).unwrap()), 11387);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    days::{Part, Solution, Visualize},
+    generate::{grid_to_input, Generate},
+    render::{Color, Overlay},
+    Coords2D, Grid, Point2D,
+};
+use array2d::Array2D;
+use rand::{seq::IndexedRandom, Rng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
+
+pub struct Map {
+    tiles: Grid<char>,
+    antennas: HashMap<char, Vec<Coords2D>>,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = value.parse::<Grid<char>>()?;
+
+        let mut antennas = HashMap::<char, Vec<_>>::new();
+
+        for (position, tile) in tiles.iter() {
+            if *tile == '.' {
+                continue;
+            }
+
+            antennas.entry(*tile).or_default().push(position);
+        }
+
+        Ok(Self { tiles, antennas })
+    }
+}
+
+impl Map {
+    fn get_antinodes_for_pair(
+        &self,
+        antenna_a: Coords2D,
+        antenna_b: Coords2D,
+    ) -> (Option<Coords2D>, Option<Coords2D>) {
+        let antenna_a = Point2D::from(antenna_a);
+        let antenna_b = Point2D::from(antenna_b);
+        let v = antenna_b - antenna_a;
+
+        let antinode_a = self.tiles.point_to_coords(antenna_a - v);
+        let antinode_b = self.tiles.point_to_coords(antenna_b + v);
+
+        (antinode_a, antinode_b)
+    }
+
+    fn get_pairs_for_antenna(&self, antenna: char) -> HashSet<(Coords2D, Coords2D)> {
+        let Some(positions) = self.antennas.get(&antenna) else {
+            return HashSet::new();
+        };
+
+        let mut ret = HashSet::new();
+
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                ret.insert((positions[i], positions[j]));
+            }
+        }
+
+        ret
+    }
+
+    fn get_antinodes_for_antenna(&self, antenna: char) -> HashSet<Coords2D> {
+        let pairs = self.get_pairs_for_antenna(antenna);
+        let mut res = HashSet::new();
+        for (a, b) in pairs {
+            let (antinode_a, antinode_b) = self.get_antinodes_for_pair(a, b);
+            if let Some(antinode_a) = antinode_a {
+                res.insert(antinode_a);
+            }
+            if let Some(antinode_b) = antinode_b {
+                res.insert(antinode_b);
+            }
+        }
+
+        res
+    }
+
+    fn get_antinode_positions(&self) -> HashSet<Coords2D> {
+        self.antennas
+            .keys()
+            .flat_map(|antenna| self.get_antinodes_for_antenna(*antenna))
+            .collect()
+    }
+
+    pub fn get_num_of_antinodes(&self) -> usize {
+        self.get_antinode_positions().len()
+    }
+
+    fn get_antinodes_for_antenna_with_resonant_harmonics(
+        &self,
+        antenna: char,
+    ) -> HashSet<Coords2D> {
+        let pairs = self.get_pairs_for_antenna(antenna);
+        let mut res = HashSet::new();
+
+        for (antenna_a, antenna_b) in pairs {
+            let v = Point2D::from(antenna_b) - Point2D::from(antenna_a);
+
+            let mut walker = Point2D::from(antenna_a);
+            while let Some(antinode) = self.tiles.point_to_coords(walker) {
+                res.insert(antinode);
+                walker += v;
+            }
+
+            let mut walker = Point2D::from(antenna_a);
+            while let Some(antinode) = self.tiles.point_to_coords(walker) {
+                res.insert(antinode);
+                walker -= v;
+            }
+        }
+
+        res
+    }
+
+    fn get_antinodes_with_resonant_harmonics(&self) -> HashSet<Coords2D> {
+        self.antennas
+            .keys()
+            .flat_map(|antenna| self.get_antinodes_for_antenna_with_resonant_harmonics(*antenna))
+            .collect()
+    }
+
+    pub fn get_num_of_antinodes_with_resonant_harmonics(&self) -> usize {
+        self.get_antinodes_with_resonant_harmonics().len()
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Answer1 {
+        map.get_num_of_antinodes()
+    }
+
+    fn part2(map: &Self::Input) -> Self::Answer2 {
+        map.get_num_of_antinodes_with_resonant_harmonics()
+    }
+}
+
+impl Visualize for Day8 {
+    type Cell = char;
+
+    fn cells(map: &Self::Input) -> &Array2D<char> {
+        map.tiles.as_array()
+    }
+
+    fn palette(tile: &char) -> [u8; 3] {
+        match tile {
+            '.' => [235, 235, 235],
+            _ => [120, 120, 120],
+        }
+    }
+
+    fn overlays(map: &Self::Input, part: Part) -> Vec<Overlay> {
+        let mut antinodes = match part {
+            Part::One => map.get_antinode_positions(),
+            Part::Two => map.get_antinodes_with_resonant_harmonics(),
+        }
+        .into_iter()
+        .collect::<Vec<_>>();
+        antinodes.sort_by_key(|p| (p.row, p.column));
+
+        let antennas = map.antennas.values().flatten().copied();
+        vec![
+            Overlay::new(antennas, Color::Cyan),
+            Overlay::new(antinodes, Color::Red).with_glyph('#'),
+        ]
+    }
+}
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generate for Day8 {
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        let mut tiles = Grid::new(Array2D::filled_with('.', size, size));
+        for _ in 0..size * size / 12 {
+            let frequency = *FREQUENCIES
+                .choose(rng)
+                .expect("Frequencies should not be empty");
+            let pos = Coords2D::new(rng.random_range(0..size), rng.random_range(0..size));
+            tiles[pos] = frequency as char;
+        }
+
+        grid_to_input(&tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    const PAIR_EXAMPLE: &str = "\
+..........
+..........
+..........
+....a.....
+..........
+.....a....
+..........
+..........
+..........
+..........
+";
+
+    const T_EXAMPLE: &str = "\
+T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+";
+
+    #[test]
+    fn pair_of_antennas_has_two_antinodes() {
+        let map = Day8::parse(PAIR_EXAMPLE).unwrap();
+        let expected = HashSet::from([(1, 3), (7, 6)].map(Coords2D::from));
+        assert_eq!(map.get_antinode_positions(), expected);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE).unwrap()), 14);
+    }
+
+    #[test]
+    fn resonant_harmonics_include_antennas() {
+        let map = Day8::parse(T_EXAMPLE).unwrap();
+        assert_eq!(map.get_num_of_antinodes_with_resonant_harmonics(), 9);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day8::part2(&Day8::parse(EXAMPLE).unwrap()), 34);
+    }
+}
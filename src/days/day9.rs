@@ -0,0 +1,211 @@
+use crate::{days::Solution, generate::Generate};
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+pub fn calculate_compact_filesystem_checksum_with_simple_algorithm(disk_map_str: &str) -> usize {
+    let mut disk_map = disk_map_str
+        .chars()
+        .map(|c| c.to_digit(10).expect("Input should only have digits"))
+        .collect::<Vec<_>>();
+
+    let mut result = 0usize;
+    let mut position_on_disk = 0;
+
+    let mut front_idx = 0usize;
+    let mut back_idx = disk_map.len() - 1;
+    if back_idx % 2 == 1 {
+        // If we end with an empty space, move back to last file
+        back_idx -= 1;
+    }
+
+    while front_idx < disk_map.len() {
+        if front_idx.is_multiple_of(2) {
+            // Even: block (0, 2, 4, ..)
+            let file_id = front_idx / 2;
+            let block_size = disk_map[front_idx];
+            if block_size == 0 {
+                break;
+            }
+            for _ in 0..block_size {
+                result += file_id * position_on_disk;
+                position_on_disk += 1;
+            }
+            disk_map[front_idx] = 0;
+        } else {
+            // Odd: empty (1, 3, 5, ..)
+            let empty_block_size = disk_map[front_idx];
+            let mut file_id = back_idx / 2;
+
+            for _ in 0..empty_block_size {
+                if disk_map[back_idx] == 0 {
+                    if back_idx <= front_idx + 2 {
+                        break;
+                    }
+                    back_idx -= 2;
+                    file_id = back_idx / 2;
+                }
+                result += file_id * position_on_disk;
+                position_on_disk += 1;
+                disk_map[back_idx] -= 1;
+            }
+        }
+        front_idx += 1;
+    }
+    result
+}
+
+pub fn calculate_compact_filesystem_checksum_with_full_file_algorithm(disk_map_str: &str) -> usize {
+    let disk_map = disk_map_str
+        .chars()
+        .map(|c| c.to_digit(10).expect("Input should only have digits") as usize)
+        .collect::<Vec<_>>();
+
+    let (file_blocks, mut empty_blocks) = {
+        let mut file_blocks = Vec::new();
+        let mut empty_blocks = Vec::new();
+        let mut position = 0;
+        for (i, &block_size) in disk_map.iter().enumerate() {
+            if i % 2 == 1 {
+                // Odd: empty block
+                empty_blocks.push((block_size, position))
+            } else {
+                // Even: file block
+                file_blocks.push(position);
+            }
+            position += block_size;
+        }
+
+        (file_blocks, empty_blocks)
+    };
+
+    let mut result = 0;
+
+    for i in (0..disk_map.len()).rev().step_by(2) {
+        let file_id = i / 2;
+        let file_block_size = disk_map[i];
+
+        if let Some(idx_of_empty_block_with_enough_space) = empty_blocks
+            .iter()
+            .take(file_id)
+            .position(|(empty_block_size, _)| *empty_block_size >= file_block_size)
+        {
+            for k in 0..file_block_size {
+                result += (empty_blocks[idx_of_empty_block_with_enough_space].1 + k) * file_id;
+            }
+
+            empty_blocks[idx_of_empty_block_with_enough_space].0 -= file_block_size;
+            empty_blocks[idx_of_empty_block_with_enough_space].1 += file_block_size;
+        } else {
+            for k in 0..file_block_size {
+                result += (file_blocks[file_id] + k) * file_id
+            }
+        }
+    }
+
+    result
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let disk_map = input.trim_end();
+        anyhow::ensure!(!disk_map.is_empty(), "Input should have a disk map");
+        anyhow::ensure!(
+            disk_map.chars().all(|c| c.is_ascii_digit()),
+            "Input should only have digits"
+        );
+        Ok(disk_map.to_owned())
+    }
+
+    fn part1(disk_map: &Self::Input) -> Self::Answer1 {
+        calculate_compact_filesystem_checksum_with_simple_algorithm(disk_map)
+    }
+
+    fn part2(disk_map: &Self::Input) -> Self::Answer2 {
+        calculate_compact_filesystem_checksum_with_full_file_algorithm(disk_map)
+    }
+}
+
+impl Generate for Day9 {
+    const DEFAULT_SIZE: usize = 10000;
+
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String {
+        // Files take at least one block, free space can be empty
+        let mut disk_map = String::new();
+        for i in 0..size {
+            if i > 0 {
+                disk_map += &rng.random_range(0..=9).to_string();
+            }
+            disk_map += &rng.random_range(1..=9).to_string();
+        }
+        disk_map.push('\n');
+
+        disk_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402";
+
+    #[test]
+    fn small_example_moving_blocks() {
+        // 0..111....22222 compacts to 022111222
+        assert_eq!(
+            calculate_compact_filesystem_checksum_with_simple_algorithm("12345"),
+            2 + 2 * 2 + 3 + 4 + 5 + 6 * 2 + 7 * 2 + 8 * 2
+        );
+    }
+
+    #[test]
+    fn disk_maps_without_gaps_to_fill() {
+        assert_eq!(
+            calculate_compact_filesystem_checksum_with_simple_algorithm("8"),
+            0
+        );
+        // 0..
+        assert_eq!(
+            calculate_compact_filesystem_checksum_with_simple_algorithm("12"),
+            0
+        );
+        // 0..1 compacts to 01
+        assert_eq!(
+            calculate_compact_filesystem_checksum_with_simple_algorithm("121"),
+            1
+        );
+    }
+
+    #[test]
+    fn rejects_empty_disk_map() {
+        assert!(Day9::parse("").is_err());
+        assert!(Day9::parse(" \n").is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day9::part1(&Day9::parse(EXAMPLE).unwrap()), 1928);
+    }
+
+    #[test]
+    fn small_example_moving_whole_files() {
+        // Neither file fits into an earlier gap, so 0..111....22222 stays as it is
+        assert_eq!(
+            calculate_compact_filesystem_checksum_with_full_file_algorithm("12345"),
+            (3 + 4 + 5) + (10 + 11 + 12 + 13 + 14) * 2
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day9::part2(&Day9::parse(EXAMPLE).unwrap()), 2858);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    bench::{self, Benchmark},
+    export::Image,
+    generate::{self, Generate},
+    render::{Color, Overlay, Renderer},
+    try_open_input, try_read_input, Coords2D, InputError,
+};
+use array2d::Array2D;
+use clap::Parser;
+use std::{
+    collections::HashMap,
+    fmt, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = match self {
+            Self::One => 1,
+            Self::Two => 2,
+        };
+        write!(f, "{n}")
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            s => anyhow::bail!("Part should be 1 or 2, got {s}"),
+        }
+    }
+}
+
+/// The checked-in input of `day`, independent of the working directory.
+pub fn default_input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("input/day{day}.txt"))
+}
+
+// Command line arguments shared by the single-day binaries
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solution for a single day")]
+pub struct DayArgs {
+    /// Input file to use instead of `input/dayN.txt`, or `-` for stdin
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+}
+
+impl DayArgs {
+    pub fn read_input(&self, day: u8) -> Result<String, InputError> {
+        match &self.input {
+            Some(path) => try_read_input(path),
+            None => try_read_input(default_input_path(day)),
+        }
+    }
+
+    pub fn open_input(&self, day: u8) -> Result<Box<dyn io::BufRead>, InputError> {
+        match &self.input {
+            Some(path) => try_open_input(path),
+            None => try_open_input(default_input_path(day)),
+        }
+    }
+}
+
+/// A puzzle day: parses its input once, then answers both parts from the parsed input.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// A grid day that can highlight what each part is looking for on top of its input.
+pub trait Visualize: Solution {
+    type Cell: fmt::Display;
+
+    fn cells(input: &Self::Input) -> &Array2D<Self::Cell>;
+
+    /// Colour of a cell in exported images, before any overlays are drawn.
+    fn palette(cell: &Self::Cell) -> [u8; 3];
+
+    /// The cells `part` is about, in the order they should be revealed when animated.
+    fn overlays(input: &Self::Input, part: Part) -> Vec<Overlay>;
+
+    /// How many times each cell is visited while solving `part`, for days that simulate a walk.
+    fn visit_counts(_input: &Self::Input, _part: Part) -> Option<HashMap<Coords2D, usize>> {
+        None
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> anyhow::Result<Vec<String>> {
+    let input = S::parse(input)?;
+    let answers = parts
+        .iter()
+        .map(|part| match part {
+            Part::One => S::part1(&input).to_string(),
+            Part::Two => S::part2(&input).to_string(),
+        })
+        .collect();
+
+    Ok(answers)
+}
+
+fn visualize<S: Visualize>(input: &str, part: Part) -> anyhow::Result<Renderer> {
+    let input = S::parse(input)?;
+    Ok(Renderer::new(S::cells(&input)).with_overlays(S::overlays(&input, part)))
+}
+
+fn draw<S: Visualize>(input: &str, part: Part) -> anyhow::Result<Image> {
+    let input = S::parse(input)?;
+    let image = S::overlays(&input, part).iter().fold(
+        Image::new(S::cells(&input), S::palette),
+        Image::with_overlay,
+    );
+
+    Ok(match S::visit_counts(&input, part) {
+        Some(counts) => image.with_heatmap(&counts, Color::Red),
+        None => image,
+    })
+}
+
+/// An entry in the registry of days the `aoc` runner can dispatch to.
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, &[Part]) -> anyhow::Result<Vec<String>>,
+    bench: fn(&str, usize) -> anyhow::Result<Benchmark>,
+    generate: fn(u64, Option<usize>) -> String,
+    visualize: Option<fn(&str, Part) -> anyhow::Result<Renderer>>,
+    draw: Option<fn(&str, Part) -> anyhow::Result<Image>>,
+}
+
+impl Day {
+    const fn new<S: Generate>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            bench: bench::run::<S>,
+            generate: generate::run::<S>,
+            visualize: None,
+            draw: None,
+        }
+    }
+
+    const fn with_visualization<S: Visualize>(mut self) -> Self {
+        self.visualize = Some(visualize::<S>);
+        self.draw = Some(draw::<S>);
+        self
+    }
+
+    pub fn default_input_path(&self) -> PathBuf {
+        default_input_path(self.number)
+    }
+
+    /// Parses `input` and returns the answers to `parts`, in the same order.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> anyhow::Result<Vec<String>> {
+        (self.solve)(input, parts)
+    }
+
+    pub fn benchmark(&self, input: &str, iterations: usize) -> anyhow::Result<Benchmark> {
+        (self.bench)(input, iterations)
+    }
+
+    /// A random input made from `seed`, as big as the real one unless `size` is given.
+    pub fn generate(&self, seed: u64, size: Option<usize>) -> String {
+        (self.generate)(seed, size)
+    }
+
+    /// Parses `input` and highlights the cells `part` is about, if this day has a grid to show.
+    pub fn visualize(&self, input: &str, part: Part) -> Option<anyhow::Result<Renderer>> {
+        self.visualize.map(|visualize| visualize(input, part))
+    }
+
+    /// Like [`Self::visualize`], but as an image that can be saved as SVG or PNG.
+    pub fn draw(&self, input: &str, part: Part) -> Option<anyhow::Result<Image>> {
+        self.draw.map(|draw| draw(input, part))
+    }
+}
+
+// To add a new day, create its module above and register it here.
+pub const DAYS: [Day; 10] = [
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>().with_visualization::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>().with_visualization::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>().with_visualization::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>().with_visualization::<day10::Day10>(),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,236 @@
+//! Drawing 2D arrays, with overlays and heatmaps on top of them, as SVG or PNG images.
+
+use array2d::Array2D;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    map_array,
+    render::{Color, Overlay},
+    Coords2D,
+};
+
+const DEFAULT_CELL_SIZE: u32 = 8;
+
+/// A picture with one solid square per cell of an array.
+#[derive(Clone, Debug)]
+pub struct Image {
+    pixels: Array2D<[u8; 3]>,
+    cell_size: u32,
+}
+
+impl Image {
+    /// Starts from `array`, coloring each cell with `palette`.
+    pub fn new<T>(array: &Array2D<T>, palette: impl Fn(&T) -> [u8; 3]) -> Self {
+        Self {
+            pixels: map_array(array, palette),
+            cell_size: DEFAULT_CELL_SIZE,
+        }
+    }
+
+    /// Side length of each cell, in pixels.
+    pub fn with_cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+
+    /// Paints the cells of `overlay` in its colour, over whatever was drawn before.
+    pub fn with_overlay(mut self, overlay: &Overlay) -> Self {
+        for position in &overlay.cells {
+            if let Some(pixel) = self.pixels.get_mut(position.row, position.column) {
+                *pixel = overlay.color.rgb();
+            }
+        }
+        self
+    }
+
+    /// Tints each cell towards `color` in proportion to its count, so the most counted cell is
+    /// drawn in `color` itself.
+    pub fn with_heatmap(mut self, counts: &HashMap<Coords2D, usize>, color: Color) -> Self {
+        let Some(&max) = counts.values().max() else {
+            return self;
+        };
+
+        for (position, &count) in counts {
+            if let Some(pixel) = self.pixels.get_mut(position.row, position.column) {
+                let weight = 0.25 + 0.75 * count as f64 / max.max(1) as f64;
+                *pixel = blend(*pixel, color.rgb(), weight);
+            }
+        }
+        self
+    }
+
+    /// Width and height in pixels, or an error if the pixel data would not fit in memory.
+    fn dimensions(&self) -> io::Result<(usize, usize)> {
+        let size = self.cell_size as usize;
+        let (columns, rows) = (self.pixels.num_columns(), self.pixels.num_rows());
+        let too_large = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("An image of {columns}x{rows} cells of {size} pixels is too large"),
+            )
+        };
+
+        let width = columns.checked_mul(size).ok_or_else(too_large)?;
+        let height = rows.checked_mul(size).ok_or_else(too_large)?;
+        width
+            .checked_mul(height)
+            .and_then(|area| area.checked_mul(3))
+            .ok_or_else(too_large)?;
+        Ok((width, height))
+    }
+
+    pub fn write_svg(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = self.dimensions()?;
+        let size = self.cell_size as usize;
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#
+        )?;
+        for (row, cells) in self.pixels.rows_iter().enumerate() {
+            for (column, [r, g, b]) in cells.enumerate() {
+                let (x, y) = (column * size, row * size);
+                writeln!(
+                    out,
+                    r#"<rect x="{x}" y="{y}" width="{size}" height="{size}" fill="rgb({r},{g},{b})"/>"#
+                )?;
+            }
+        }
+        writeln!(out, "</svg>")
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let (width, height) = self.dimensions()?;
+        let too_large = |_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("A PNG of {width}x{height} pixels is too large"),
+            )
+        };
+        let mut encoder = png::Encoder::new(
+            out,
+            u32::try_from(width).map_err(too_large)?,
+            u32::try_from(height).map_err(too_large)?,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let size = self.cell_size as usize;
+        let mut data = Vec::with_capacity(width * height * 3);
+        for row in self.pixels.rows_iter() {
+            let line = row
+                .flat_map(|pixel| std::iter::repeat_n(pixel, size))
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            for _ in 0..size {
+                data.extend_from_slice(&line);
+            }
+        }
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&data).map_err(io::Error::other)
+    }
+
+    /// Writes the image to `path`, as a PNG if its extension is `png` and as an SVG otherwise.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut out = BufWriter::new(File::create(path)?);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => self.write_png(&mut out)?,
+            _ => self.write_svg(&mut out)?,
+        }
+        out.flush()
+    }
+}
+
+fn blend(from: [u8; 3], to: [u8; 3], weight: f64) -> [u8; 3] {
+    std::array::from_fn(|i| {
+        let (from, to) = (f64::from(from[i]), f64::from(to[i]));
+        (from + (to - from) * weight).round() as u8
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_by_two() -> Image {
+        let array = Array2D::from_rows(&[vec![0u8, 1], vec![1, 0]]).unwrap();
+        Image::new(&array, |&cell| [cell * 255; 3])
+    }
+
+    #[test]
+    fn svg_of_small_grid_with_overlay() {
+        let overlay = Overlay::new([Coords2D::new(1, 0)], Color::Red);
+        let image = two_by_two().with_cell_size(2).with_overlay(&overlay);
+
+        let mut out = Vec::new();
+        image.write_svg(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="4" viewBox="0 0 4 4" shape-rendering="crispEdges">"#,
+                "\n",
+                r#"<rect x="0" y="0" width="2" height="2" fill="rgb(0,0,0)"/>"#,
+                "\n",
+                r#"<rect x="2" y="0" width="2" height="2" fill="rgb(255,255,255)"/>"#,
+                "\n",
+                r#"<rect x="0" y="2" width="2" height="2" fill="rgb(220,50,47)"/>"#,
+                "\n",
+                r#"<rect x="2" y="2" width="2" height="2" fill="rgb(0,0,0)"/>"#,
+                "\n",
+                "</svg>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let overlay = Overlay::new([Coords2D::new(1, 0)], Color::Blue);
+        let image = two_by_two().with_cell_size(3).with_overlay(&overlay);
+
+        let mut out = Vec::new();
+        image.write_png(&mut out).unwrap();
+
+        let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (6, 6));
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+
+        // The last pixel of the bottom-left cell
+        let offset = 5 * info.line_size + 2 * 3;
+        assert_eq!(data[offset..offset + 3], Color::Blue.rgb());
+    }
+
+    #[test]
+    fn most_visited_cell_is_hottest() {
+        let counts = HashMap::from([
+            (Coords2D::new(0, 0), 1),
+            (Coords2D::new(0, 1), 4),
+            (Coords2D::new(1, 1), 2),
+        ]);
+        let image = two_by_two().with_heatmap(&counts, Color::Red);
+
+        assert_eq!(image.pixels[(0, 1)], Color::Red.rgb());
+        assert_ne!(image.pixels[(0, 0)], Color::Red.rgb());
+        assert_ne!(image.pixels[(1, 1)], Color::Red.rgb());
+        // Cells that were never visited keep their own colour
+        assert_eq!(image.pixels[(1, 0)], [255; 3]);
+    }
+
+    #[test]
+    fn rejects_png_wider_than_u32() {
+        let image =
+            Image::new(&Array2D::filled_with(0, 1, 70_000), |_| [0; 3]).with_cell_size(1 << 16);
+        assert_eq!(
+            image.write_png(io::sink()).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Seedable random puzzle inputs, for testing the solvers at scales far beyond the real inputs.
+//! Inputs are drawn from `ChaCha8Rng`, whose output is specified independently of the platform
+//! and of `rand` releases, so the same seed and size produce the same input for as long as the
+//! generators and the `rand` sampling methods they use stay the same.
+
+use crate::{days::Solution, Grid};
+use array2d::Array2D;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// A day that can make up valid inputs for itself.
+pub trait Generate: Solution {
+    /// Size of the real puzzle input, in the unit `generate` takes its size in.
+    const DEFAULT_SIZE: usize;
+
+    /// An input of the given size, which is the number of lines for line-based days and the side
+    /// length for grid days.
+    fn generate(rng: &mut ChaCha8Rng, size: usize) -> String;
+}
+
+pub(crate) fn run<S: Generate>(seed: u64, size: Option<usize>) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    S::generate(&mut rng, size.unwrap_or(S::DEFAULT_SIZE))
+}
+
+/// A square grid with every cell drawn by `cell`.
+pub fn random_grid(
+    rng: &mut ChaCha8Rng,
+    size: usize,
+    mut cell: impl FnMut(&mut ChaCha8Rng) -> char,
+) -> Grid<char> {
+    Grid::new(Array2D::filled_by_row_major(|| cell(rng), size, size))
+}
+
+/// `grid` as puzzle input, one line per row.
+pub fn grid_to_input(grid: &Grid<char>) -> String {
+    format!("{grid}\n")
+}
@@ -1,12 +1,154 @@
+pub mod bench;
+pub mod days;
+pub mod export;
+pub mod generate;
+pub mod parse;
+pub mod regions;
+pub mod render;
+pub mod search;
+
 use array2d::Array2D;
-use std::{convert::TryInto, fs::read_to_string, path::Path};
+use std::{
+    convert::TryInto,
+    fmt,
+    fs::{read_to_string, File},
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub enum InputErrorKind {
+    Io(io::Error),
+    RaggedRow { expected: usize, found: usize },
+    UnexpectedChar(char),
+    InvalidLine(String),
+}
+
+/// An error produced while loading or parsing puzzle input. Line and column numbers are 1-based.
+#[derive(Debug)]
+pub struct InputError {
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub kind: InputErrorKind,
+}
+
+impl InputError {
+    pub fn new(kind: InputErrorKind) -> Self {
+        Self {
+            path: None,
+            line: None,
+            column: None,
+            kind,
+        }
+    }
+
+    pub fn at(mut self, line: usize, column: Option<usize>) -> Self {
+        self.line = Some(line);
+        self.column = column;
+        self
+    }
+
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.path.as_ref().map(|p| p.display().to_string()),
+            self.line.map(|l| l.to_string()),
+            self.column.map(|c| c.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+
+        match &self.kind {
+            InputErrorKind::Io(_) => write!(f, "failed to read input"),
+            InputErrorKind::RaggedRow { expected, found } => write!(
+                f,
+                "row has {found} elements, but previous rows have {expected}"
+            ),
+            InputErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {c:?}"),
+            InputErrorKind::InvalidLine(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            InputErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+fn unwrap_input<T>(result: Result<T, InputError>) -> T {
+    result.unwrap_or_else(|e| panic!("{e}"))
+}
+
+pub fn try_read_file_to_string(path: impl AsRef<Path>) -> Result<String, InputError> {
+    read_to_string(&path).map_err(|e| InputError::new(InputErrorKind::Io(e)).with_path(path))
+}
+
+/// Reads puzzle input from `path`, or from stdin if `path` is `-`.
+pub fn try_read_input(path: impl AsRef<Path>) -> Result<String, InputError> {
+    if path.as_ref() == Path::new("-") {
+        io::read_to_string(io::stdin())
+            .map_err(|e| InputError::new(InputErrorKind::Io(e)).with_path("<stdin>"))
+    } else {
+        try_read_file_to_string(path)
+    }
+}
+
+/// Opens puzzle input at `path`, or stdin if `path` is `-`, for inputs too large to read at once.
+pub fn try_open_input(path: impl AsRef<Path>) -> Result<Box<dyn io::BufRead>, InputError> {
+    if path.as_ref() == Path::new("-") {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        File::open(&path)
+            .map(|file| Box::new(io::BufReader::new(file)) as Box<dyn io::BufRead>)
+            .map_err(|e| InputError::new(InputErrorKind::Io(e)).with_path(path))
+    }
+}
 
 pub fn read_file_to_string(path: impl AsRef<Path>) -> String {
-    read_to_string(path).unwrap()
+    unwrap_input(try_read_file_to_string(path))
+}
+
+pub fn try_read_file_lines(path: impl AsRef<Path>) -> Result<Vec<String>, InputError> {
+    let contents = try_read_file_to_string(path)?;
+    Ok(contents.lines().map(str::to_string).collect())
 }
 
 pub fn read_file_lines(path: impl AsRef<Path>) -> Vec<String> {
-    read_file_lines_as(path, str::to_string)
+    unwrap_input(try_read_file_lines(path))
+}
+
+pub fn try_read_file_lines_as<T, E: fmt::Display>(
+    path: impl AsRef<Path>,
+    f: fn(&str) -> Result<T, E>,
+) -> Result<Vec<T>, InputError> {
+    let contents = try_read_file_to_string(&path)?;
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            f(line).map_err(|e| {
+                InputError::new(InputErrorKind::InvalidLine(e.to_string()))
+                    .at(i + 1, None)
+                    .with_path(&path)
+            })
+        })
+        .collect()
 }
 
 pub fn read_file_lines_as<T>(path: impl AsRef<Path>, f: fn(&str) -> T) -> Vec<T> {
@@ -20,19 +162,52 @@ pub fn read_file_lines_filter_as<T>(path: impl AsRef<Path>, f: fn(&str) -> Optio
 }
 
 pub fn read_file_lines_extract_first(path: impl AsRef<Path>) -> (String, Vec<String>) {
-    let mut input = read_file_lines(path)
-        .iter()
-        .filter_map(|line| {
-            if line.is_empty() {
-                None
-            } else {
-                Some(line.to_owned())
+    let contents = read_file_to_string(path);
+    let mut input = split_sections(&contents)
+        .into_iter()
+        .flat_map(str::lines)
+        .map(str::to_owned);
+    let first = input
+        .next()
+        .expect("input should have at least one non-empty line");
+
+    (first, input.collect())
+}
+
+/// Splits `s` into sections separated by one or more blank lines. Works the same for `\n` and
+/// `\r\n` line endings; the returned sections do not include the line ending of their last line.
+pub fn split_sections(s: &str) -> Vec<&str> {
+    split_sections_with_lines(s)
+        .into_iter()
+        .map(|(_, section)| section)
+        .collect()
+}
+
+/// Like [`split_sections`], but also returns the 1-based line number each section starts on, so
+/// errors within a section can be reported against the whole input.
+pub fn split_sections_with_lines(s: &str) -> Vec<(usize, &str)> {
+    let mut sections = Vec::new();
+    let mut section_start = None;
+    let mut section_end = 0;
+    let mut offset = 0;
+
+    for (i, line) in s.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.trim().is_empty() {
+            if let Some((first_line, start)) = section_start.take() {
+                sections.push((first_line, &s[start..section_end]));
             }
-        })
-        .collect::<Vec<_>>();
-    let first = input.remove(0);
+        } else {
+            section_start.get_or_insert((i + 1, offset));
+            section_end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, start)) = section_start {
+        sections.push((first_line, &s[start..section_end]));
+    }
 
-    (first, input)
+    sections
 }
 
 pub fn vec_to_array<T, const N: usize>(v: Vec<T>) -> [T; N] {
@@ -40,55 +215,318 @@ pub fn vec_to_array<T, const N: usize>(v: Vec<T>) -> [T; N] {
         .unwrap_or_else(|v: Vec<T>| panic!("Expected a Vec of length {} but it was {}", N, v.len()))
 }
 
-pub fn print_2d_array<T: Clone + std::fmt::Display>(array: &Array2D<T>) {
-    for row in array.rows_iter() {
-        for column in row.into_iter() {
-            print!("{column}",);
+/// Iterator over the runs of ASCII digits in a string, see [`integer_substrings`].
+#[derive(Clone, Debug)]
+pub struct IntegerSubstrings<'a> {
+    s: &'a str,
+    position: usize,
+    signed: bool,
+}
+
+impl<'a> Iterator for IntegerSubstrings<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.s.as_bytes();
+        let first_digit =
+            self.position + bytes[self.position..].iter().position(u8::is_ascii_digit)?;
+        let end = first_digit
+            + bytes[first_digit..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - first_digit);
+        let start = match self.signed && first_digit > self.position {
+            true if bytes[first_digit - 1] == b'-' => first_digit - 1,
+            _ => first_digit,
+        };
+
+        self.position = end;
+        Some(&self.s[start..end])
+    }
+}
+
+/// Every run of digits in `s`, including a `-` right in front of it if `signed` is set.
+pub fn integer_substrings(s: &str, signed: bool) -> IntegerSubstrings<'_> {
+    IntegerSubstrings {
+        s,
+        position: 0,
+        signed,
+    }
+}
+
+fn parse_integer<T: FromStr>(s: &str) -> Result<T, InputError> {
+    s.parse().map_err(|_| {
+        let message = format!(
+            "integer {s:?} does not fit in {}",
+            std::any::type_name::<T>()
+        );
+        InputError::new(InputErrorKind::InvalidLine(message))
+    })
+}
+
+/// Every integer in `s`, ignoring any `-` signs, without allocating.
+///
+/// # Panics
+///
+/// Panics when reaching an integer that does not fit in `T`.
+pub fn iter_unsigned_integers<T: FromStr>(s: &str) -> impl Iterator<Item = T> + use<'_, T> {
+    integer_substrings(s, false).map(|integer| unwrap_input(parse_integer(integer)))
+}
+
+/// Every integer in `s`, negative if directly preceded by a `-`, without allocating.
+///
+/// # Panics
+///
+/// Panics when reaching an integer that does not fit in `T`, including negative integers when
+/// `T` is unsigned.
+pub fn iter_signed_integers<T: FromStr>(s: &str) -> impl Iterator<Item = T> + use<'_, T> {
+    integer_substrings(s, true).map(|integer| unwrap_input(parse_integer(integer)))
+}
+
+/// Every integer in `s`, ignoring any `-` signs, or an error if one does not fit in `T`.
+pub fn try_extract_unsigned_integers<T: FromStr>(s: &str) -> Result<Vec<T>, InputError> {
+    integer_substrings(s, false).map(parse_integer).collect()
+}
+
+/// Every integer in `s`, negative if directly preceded by a `-`, or an error if one does not fit
+/// in `T`.
+pub fn try_extract_signed_integers<T: FromStr>(s: &str) -> Result<Vec<T>, InputError> {
+    integer_substrings(s, true).map(parse_integer).collect()
+}
+
+/// # Panics
+///
+/// Panics if an integer does not fit in `T`, see [`try_extract_unsigned_integers`].
+pub fn extract_unsigned_integers<T: FromStr>(s: &str) -> Vec<T> {
+    unwrap_input(try_extract_unsigned_integers(s))
+}
+
+/// # Panics
+///
+/// Panics if an integer does not fit in `T`, see [`try_extract_signed_integers`].
+pub fn extract_signed_integers<T: FromStr>(s: &str) -> Vec<T> {
+    unwrap_input(try_extract_signed_integers(s))
+}
+
+/// Like [`extract_unsigned_integers`], but panics unless there are exactly `N` integers.
+pub fn extract_unsigned_integers_array<T: FromStr, const N: usize>(s: &str) -> [T; N] {
+    vec_to_array(extract_unsigned_integers(s))
+}
+
+/// Like [`extract_signed_integers`], but panics unless there are exactly `N` integers.
+pub fn extract_signed_integers_array<T: FromStr, const N: usize>(s: &str) -> [T; N] {
+    vec_to_array(extract_signed_integers(s))
+}
+
+/// How to lay out the cells of an [`Array2D`] as text: every cell is right-aligned to the same
+/// width, optionally with a frame around the cells and row/column indices along the edges.
+#[derive(Clone, Debug, Default)]
+pub struct ArrayFormat {
+    cell_width: Option<usize>,
+    delimiter: String,
+    borders: bool,
+    headers: bool,
+}
+
+impl ArrayFormat {
+    /// Cells written back to back, as wide as the widest one.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pads every cell to `width` instead of the width of the widest cell.
+    pub fn with_cell_width(mut self, width: usize) -> Self {
+        self.cell_width = Some(width);
+        self
+    }
+
+    /// Written between neighboring cells of a row.
+    pub fn with_delimiter(mut self, delimiter: &str) -> Self {
+        self.delimiter = delimiter.to_string();
+        self
+    }
+
+    pub fn with_borders(mut self) -> Self {
+        self.borders = true;
+        self
+    }
+
+    pub fn with_headers(mut self) -> Self {
+        self.headers = true;
+        self
+    }
+
+    pub fn display<'a, T: fmt::Display>(&'a self, array: &'a Array2D<T>) -> ArrayDisplay<'a, T> {
+        ArrayDisplay {
+            format: self,
+            array,
         }
-        println!();
+    }
+
+    pub fn write<T: fmt::Display>(
+        &self,
+        array: &Array2D<T>,
+        out: &mut impl fmt::Write,
+    ) -> fmt::Result {
+        write!(out, "{}", self.display(array))
+    }
+
+    pub fn write_io<T: fmt::Display>(
+        &self,
+        array: &Array2D<T>,
+        out: &mut impl io::Write,
+    ) -> io::Result<()> {
+        write!(out, "{}", self.display(array))
+    }
+
+    pub fn format<T: fmt::Display>(&self, array: &Array2D<T>) -> String {
+        self.display(array).to_string()
     }
 }
 
-pub fn print_u8_2d_array_with_delim(array: &Array2D<u8>) {
-    for row in array.rows_iter() {
-        print!("|");
-        for column in row.into_iter() {
-            print!("{column:2}|",);
+/// An [`Array2D`] laid out according to an [`ArrayFormat`], one line per row.
+pub struct ArrayDisplay<'a, T> {
+    format: &'a ArrayFormat,
+    array: &'a Array2D<T>,
+}
+
+impl<T: fmt::Display> ArrayDisplay<'_, T> {
+    fn cell_width(&self, cells: &[Vec<String>]) -> usize {
+        self.format.cell_width.unwrap_or_else(|| {
+            let widest_cell = cells
+                .iter()
+                .flatten()
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0);
+            let widest_header = match self.format.headers {
+                true => num_digits(self.array.num_columns().saturating_sub(1)),
+                false => 0,
+            };
+            widest_cell.max(widest_header)
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ArrayDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ArrayFormat {
+            delimiter,
+            borders,
+            headers,
+            ..
+        } = self.format;
+
+        let cells = self
+            .array
+            .rows_iter()
+            .map(|row| row.map(ToString::to_string).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = self.cell_width(&cells);
+        let num_columns = self.array.num_columns();
+
+        let margin = match headers {
+            true => num_digits(self.array.num_rows().saturating_sub(1)) + 1,
+            false => 0,
+        };
+        let (left, right) = match borders {
+            true => ("|", "|"),
+            false => ("", ""),
+        };
+        let inner_width =
+            num_columns * width + num_columns.saturating_sub(1) * delimiter.chars().count();
+        let border_line = format!("{:margin$}+{}+", "", "-".repeat(inner_width));
+
+        if *headers {
+            let spacing = " ".repeat(delimiter.chars().count());
+            let indices = (0..num_columns)
+                .map(|column| format!("{column:>width$}"))
+                .collect::<Vec<_>>()
+                .join(&spacing);
+            writeln!(f, "{:margin$}{:pad$}{indices}", "", "", pad = left.len())?;
         }
-        println!();
+        if *borders {
+            writeln!(f, "{border_line}")?;
+        }
+        for (i, row) in cells.iter().enumerate() {
+            if *headers {
+                write!(f, "{i:>w$} ", w = margin - 1)?;
+            }
+            let row = row
+                .iter()
+                .map(|cell| format!("{cell:>width$}"))
+                .collect::<Vec<_>>()
+                .join(delimiter);
+            writeln!(f, "{left}{row}{right}")?;
+        }
+        if *borders {
+            writeln!(f, "{border_line}")?;
+        }
+
+        Ok(())
     }
 }
 
-pub fn print_usize_2d_array_with_delim(array: &Array2D<usize>) {
-    for row in array.rows_iter() {
-        print!("|");
-        for column in row.into_iter() {
-            print!("{column:5}|",);
+fn num_digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |digits| digits as usize + 1)
+}
+
+fn try_parse_2d_grid<T: Clone>(
+    s: &str,
+    f: impl Fn(char) -> Option<T>,
+) -> Result<Array2D<T>, InputError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for (i, line) in s.lines().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(j, c)| {
+                f(c).ok_or_else(|| {
+                    InputError::new(InputErrorKind::UnexpectedChar(c)).at(i + 1, Some(j + 1))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                let kind = InputErrorKind::RaggedRow {
+                    expected: first.len(),
+                    found: row.len(),
+                };
+                // Point at the first extra character, or where the missing ones should start
+                let column = first.len().min(row.len()) + 1;
+                return Err(InputError::new(kind).at(i + 1, Some(column)));
+            }
         }
-        println!();
+
+        rows.push(row);
     }
+
+    Ok(Array2D::from_rows(&rows).expect("All rows have the same length as we just checked it"))
+}
+
+pub fn try_read_2d_char_array(s: &str) -> Result<Array2D<char>, InputError> {
+    try_parse_2d_grid(s, Some)
 }
 
 pub fn read_2d_char_array(s: &str) -> Array2D<char> {
-    let elements = &*s
-        .lines()
-        .map(|l| l.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+    unwrap_input(try_read_2d_char_array(s))
+}
 
-    Array2D::from_rows(elements).unwrap()
+pub fn try_parse_2d_number_grid(s: &str) -> Result<Array2D<u8>, InputError> {
+    try_parse_2d_grid(s, |d| d.to_digit(10).map(|d| d as u8))
 }
 
 pub fn parse_2d_number_grid(s: &str) -> Array2D<u8> {
-    let elements = &*s
-        .lines()
-        .map(|l| {
-            l.chars()
-                .map(|d| d.to_digit(10).unwrap() as u8)
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+    unwrap_input(try_parse_2d_number_grid(s))
+}
 
-    Array2D::from_rows(elements).unwrap()
+/// A new array of the same shape with `f` applied to every cell.
+pub fn map_array<T, U: Clone>(array: &Array2D<T>, f: impl FnMut(&T) -> U) -> Array2D<U> {
+    let values = array.elements_row_major_iter().map(f).collect::<Vec<_>>();
+    Array2D::from_row_major(&values, array.num_rows(), array.num_columns())
+        .expect("There is one value per cell")
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -147,6 +585,284 @@ impl From<(usize, usize)> for Coords2D {
     }
 }
 
+impl TryFrom<Point2D> for Coords2D {
+    type Error = Point2D;
+
+    fn try_from(value: Point2D) -> Result<Self, Self::Error> {
+        match (usize::try_from(value.row), usize::try_from(value.column)) {
+            (Ok(row), Ok(column)) => Ok(Self::new(row, column)),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Direction4; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn offset(self) -> Vec2D {
+        match self {
+            Self::Up => Vec2D::new(-1, 0),
+            Self::Right => Vec2D::new(0, 1),
+            Self::Down => Vec2D::new(1, 0),
+            Self::Left => Vec2D::new(0, -1),
+        }
+    }
+}
+
+/// Parses arrows (`^>v<`) and compass points (`NESW`), with north being up.
+impl TryFrom<char> for Direction4 {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' => Ok(Self::Up),
+            '>' | 'E' => Ok(Self::Right),
+            'v' | 'S' => Ok(Self::Down),
+            '<' | 'W' => Ok(Self::Left),
+            c => Err(c),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        let idx = Self::ALL
+            .iter()
+            .position(|&d| d == self)
+            .expect("ALL should contain every direction");
+        Self::ALL[(idx + eighths) % Self::ALL.len()]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        matches!(
+            self,
+            Self::UpRight | Self::DownRight | Self::DownLeft | Self::UpLeft
+        )
+    }
+
+    pub fn offset(self) -> Vec2D {
+        match self {
+            Self::Up => Vec2D::new(-1, 0),
+            Self::UpRight => Vec2D::new(-1, 1),
+            Self::Right => Vec2D::new(0, 1),
+            Self::DownRight => Vec2D::new(1, 1),
+            Self::Down => Vec2D::new(1, 0),
+            Self::DownLeft => Vec2D::new(1, -1),
+            Self::Left => Vec2D::new(0, -1),
+            Self::UpLeft => Vec2D::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(value: Direction4) -> Self {
+        match value {
+            Direction4::Up => Self::Up,
+            Direction4::Right => Self::Right,
+            Direction4::Down => Self::Down,
+            Direction4::Left => Self::Left,
+        }
+    }
+}
+
+/// Parses the same characters as [`Direction4`].
+impl TryFrom<char> for Direction8 {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Direction4::try_from(value).map(Self::from)
+    }
+}
+
+/// A signed displacement between two positions.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2D {
+    pub row: isize,
+    pub column: isize,
+}
+
+impl Vec2D {
+    pub const fn new(row: isize, column: isize) -> Self {
+        Self { row, column }
+    }
+}
+
+impl std::fmt::Display for Vec2D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(drow={}, dcol={})", self.row, self.column)
+    }
+}
+
+/// A signed position, which unlike [`Coords2D`] can point outside of a grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point2D {
+    pub row: isize,
+    pub column: isize,
+}
+
+impl Point2D {
+    pub const fn new(row: isize, column: isize) -> Self {
+        Self { row, column }
+    }
+}
+
+impl std::fmt::Display for Point2D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(row={}, col={})", self.row, self.column)
+    }
+}
+
+impl From<Coords2D> for Point2D {
+    fn from(value: Coords2D) -> Self {
+        Self::new(value.row as isize, value.column as isize)
+    }
+}
+
+impl std::ops::Add for Vec2D {
+    type Output = Vec2D;
+
+    fn add(self, rhs: Vec2D) -> Self::Output {
+        Vec2D::new(self.row + rhs.row, self.column + rhs.column)
+    }
+}
+
+impl std::ops::Sub for Vec2D {
+    type Output = Vec2D;
+
+    fn sub(self, rhs: Vec2D) -> Self::Output {
+        Vec2D::new(self.row - rhs.row, self.column - rhs.column)
+    }
+}
+
+impl std::ops::Mul<isize> for Vec2D {
+    type Output = Vec2D;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vec2D::new(self.row * rhs, self.column * rhs)
+    }
+}
+
+impl std::ops::Neg for Vec2D {
+    type Output = Vec2D;
+
+    fn neg(self) -> Self::Output {
+        Vec2D::new(-self.row, -self.column)
+    }
+}
+
+impl std::ops::Add<Vec2D> for Point2D {
+    type Output = Point2D;
+
+    fn add(self, rhs: Vec2D) -> Self::Output {
+        Point2D::new(self.row + rhs.row, self.column + rhs.column)
+    }
+}
+
+impl std::ops::AddAssign<Vec2D> for Point2D {
+    fn add_assign(&mut self, rhs: Vec2D) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Vec2D> for Point2D {
+    type Output = Point2D;
+
+    fn sub(self, rhs: Vec2D) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl std::ops::SubAssign<Vec2D> for Point2D {
+    fn sub_assign(&mut self, rhs: Vec2D) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::Sub for Point2D {
+    type Output = Vec2D;
+
+    fn sub(self, rhs: Point2D) -> Self::Output {
+        Vec2D::new(self.row - rhs.row, self.column - rhs.column)
+    }
+}
+
 pub trait GetNeighbors<T> {
     fn get_neighboring_indices(&self, position: Coords2D) -> Vec<Coords2D>;
 
@@ -224,3 +940,478 @@ impl<T> GetNeighbors<T> for Array2D<T> {
             .collect()
     }
 }
+
+/// A 2D grid of cells addressed by [`Coords2D`], parsed from text one character per cell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Array2D<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cells: Array2D<T>) -> Self {
+        Self { cells }
+    }
+
+    pub fn as_array(&self) -> &Array2D<T> {
+        &self.cells
+    }
+
+    pub fn into_array(self) -> Array2D<T> {
+        self.cells
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.cells.num_rows()
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.cells.num_columns()
+    }
+
+    pub fn contains(&self, position: Coords2D) -> bool {
+        position.row < self.num_rows() && position.column < self.num_columns()
+    }
+
+    /// Converts `point` to a position in the grid, or `None` if it lies outside of it.
+    pub fn point_to_coords(&self, point: Point2D) -> Option<Coords2D> {
+        Coords2D::try_from(point)
+            .ok()
+            .filter(|&position| self.contains(position))
+    }
+
+    /// Moves from `position` by `step`, or returns `None` if that would leave the grid.
+    pub fn checked_step(&self, position: Coords2D, step: Vec2D) -> Option<Coords2D> {
+        self.point_to_coords(Point2D::from(position) + step)
+    }
+
+    pub fn get(&self, position: Coords2D) -> Option<&T> {
+        self.cells.get(position.row, position.column)
+    }
+
+    pub fn get_mut(&mut self, position: Coords2D) -> Option<&mut T> {
+        self.cells.get_mut(position.row, position.column)
+    }
+
+    pub fn set(&mut self, position: Coords2D, value: T) -> Result<(), array2d::Error> {
+        self.cells.set(position.row, position.column, value)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Coords2D> + use<'_, T> {
+        self.cells.indices_row_major().map(Coords2D::from)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coords2D, &T)> {
+        self.positions().map(|position| (position, &self[position]))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Coords2D>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coords2D> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U: Clone>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(map_array(&self.cells, f))
+    }
+
+    pub fn row_iter(&self, row: usize) -> impl Iterator<Item = &T> {
+        (0..self.num_columns()).map(move |column| &self.cells[(row, column)])
+    }
+
+    pub fn column_iter(&self, column: usize) -> impl Iterator<Item = &T> {
+        (0..self.num_rows()).map(move |row| &self.cells[(row, column)])
+    }
+
+    pub fn rows_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.num_rows()).map(|row| self.row_iter(row))
+    }
+
+    pub fn columns_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.num_columns()).map(|column| self.column_iter(column))
+    }
+
+    fn walk_from(
+        &self,
+        start: Coords2D,
+        step: fn(Coords2D) -> Option<Coords2D>,
+    ) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |&position| step(position))
+            .take_while(|&position| self.contains(position))
+            .map(|position| &self[position])
+    }
+
+    /// Every diagonal running from top-left to bottom-right, starting with the one in the
+    /// bottom-left corner.
+    pub fn diagonals_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let from_left_edge = (0..self.num_rows()).rev().map(|row| Coords2D::new(row, 0));
+        let from_top_edge = (1..self.num_columns()).map(|column| Coords2D::new(0, column));
+
+        from_left_edge
+            .chain(from_top_edge)
+            .map(|start| self.walk_from(start, |p| Some(Coords2D::new(p.row + 1, p.column + 1))))
+    }
+
+    /// Every diagonal running from top-right to bottom-left, starting with the one in the
+    /// top-left corner.
+    pub fn anti_diagonals_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_column = self.num_columns().saturating_sub(1);
+        let from_top_edge = (0..self.num_columns()).map(|column| Coords2D::new(0, column));
+        let from_right_edge = (1..self.num_rows()).map(move |row| Coords2D::new(row, last_column));
+
+        from_top_edge.chain(from_right_edge).map(|start| {
+            self.walk_from(start, |p| {
+                p.column
+                    .checked_sub(1)
+                    .map(|column| Coords2D::new(p.row + 1, column))
+            })
+        })
+    }
+}
+
+impl<T> std::ops::Index<Coords2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Coords2D) -> &Self::Output {
+        &self.cells[(position.row, position.column)]
+    }
+}
+
+impl<T> std::ops::IndexMut<Coords2D> for Grid<T> {
+    fn index_mut(&mut self, position: Coords2D) -> &mut Self::Output {
+        &mut self.cells[(position.row, position.column)]
+    }
+}
+
+impl<T> From<Array2D<T>> for Grid<T> {
+    fn from(cells: Array2D<T>) -> Self {
+        Self::new(cells)
+    }
+}
+
+impl<T: TryFrom<char> + Clone> std::str::FromStr for Grid<T> {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        try_parse_2d_grid(s, |c| T::try_from(c).ok()).map(Self::new)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> GetNeighbors<T> for Grid<T> {
+    fn get_neighboring_indices(&self, position: Coords2D) -> Vec<Coords2D> {
+        self.cells.get_neighboring_indices(position)
+    }
+
+    fn get_neighboring_indices_no_diagonal(&self, position: Coords2D) -> Vec<Coords2D> {
+        self.cells.get_neighboring_indices_no_diagonal(position)
+    }
+
+    fn get_neighboring_values(&self, position: Coords2D) -> Vec<&T> {
+        self.cells.get_neighboring_values(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ragged_row_error() {
+        let error = try_read_2d_char_array("abc\nabcd\nabc").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2:4: row has 4 elements, but previous rows have 3"
+        );
+    }
+
+    #[test]
+    fn unexpected_char_error() {
+        let error = try_parse_2d_number_grid("M12\n345").unwrap_err();
+        assert_eq!(error.to_string(), "1:1: unexpected character 'M'");
+    }
+
+    #[test]
+    fn missing_file_error_names_path() {
+        let path = Path::new("input/no-such-day.txt");
+        let error = try_read_input(path).unwrap_err();
+        assert!(matches!(error.kind, InputErrorKind::Io(_)));
+        assert_eq!(error.path.as_deref(), Some(path));
+        assert!(error.to_string().starts_with("input/no-such-day.txt: "));
+    }
+
+    fn section_lines(s: &str) -> Vec<Vec<&str>> {
+        split_sections(s)
+            .into_iter()
+            .map(|section| section.lines().collect())
+            .collect()
+    }
+
+    #[test]
+    fn sections_with_lf_and_crlf() {
+        let lf = "a\nb\n\nc\n";
+        let crlf = "a\r\nb\r\n\r\nc\r\n";
+        assert_eq!(section_lines(lf), [vec!["a", "b"], vec!["c"]]);
+        assert_eq!(section_lines(crlf), section_lines(lf));
+        assert_eq!(split_sections(crlf), ["a\r\nb", "c"]);
+    }
+
+    #[test]
+    fn sections_separated_by_several_blank_lines() {
+        assert_eq!(split_sections("a\n\n\n\nb\nc"), ["a", "b\nc"]);
+    }
+
+    #[test]
+    fn sections_separated_by_whitespace_only_lines() {
+        assert_eq!(split_sections("a\n  \t\nb\n \r\nc"), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn sections_with_surrounding_blank_lines() {
+        assert_eq!(split_sections("\n\na\nb\n\n\n"), ["a\nb"]);
+        assert!(split_sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn sections_with_line_numbers() {
+        assert_eq!(
+            split_sections_with_lines("\na\nb\n\n\nc\r\n\r\nd\n"),
+            [(2, "a\nb"), (6, "c"), (8, "d")]
+        );
+    }
+
+    fn small_array() -> Array2D<u32> {
+        Array2D::from_rows(&[vec![1, 22, 3], vec![4, 5, 666]]).unwrap()
+    }
+
+    #[test]
+    fn format_with_defaults() {
+        assert_eq!(
+            ArrayFormat::new().format(&small_array()),
+            "  1 22  3\n  4  5666\n"
+        );
+    }
+
+    #[test]
+    fn format_with_cell_width() {
+        assert_eq!(
+            ArrayFormat::new().with_cell_width(4).format(&small_array()),
+            "   1  22   3\n   4   5 666\n"
+        );
+    }
+
+    #[test]
+    fn format_with_delimiter() {
+        assert_eq!(
+            ArrayFormat::new()
+                .with_delimiter(",")
+                .format(&small_array()),
+            "  1, 22,  3\n  4,  5,666\n"
+        );
+    }
+
+    #[test]
+    fn format_with_borders_and_headers() {
+        let format = ArrayFormat::new()
+            .with_delimiter(" ")
+            .with_borders()
+            .with_headers();
+        assert_eq!(
+            format.format(&small_array()),
+            concat!(
+                "     0   1   2\n",
+                "  +-----------+\n",
+                "0 |  1  22   3|\n",
+                "1 |  4   5 666|\n",
+                "  +-----------+\n",
+            )
+        );
+
+        // Row indices are right-aligned to the widest one
+        let tall = Array2D::from_row_major(&(0..22).collect::<Vec<_>>(), 11, 2).unwrap();
+        let formatted = format.format(&tall);
+        let lines = formatted.lines().collect::<Vec<_>>();
+        assert_eq!(lines[..3], ["     0  1", "   +-----+", " 0 | 0  1|"]);
+        assert_eq!(lines[11..], [" 9 |18 19|", "10 |20 21|", "   +-----+"]);
+    }
+
+    #[test]
+    fn extracts_signed_integers() {
+        assert_eq!(extract_signed_integers::<i32>("7-2"), [7, -2]);
+        assert_eq!(extract_signed_integers::<i32>("--3"), [-3]);
+        assert_eq!(
+            extract_signed_integers::<i64>("p=0,4 v=-3,-30"),
+            [0, 4, -3, -30]
+        );
+        assert_eq!(extract_unsigned_integers::<u32>("7-2 --3"), [7, 2, 3]);
+    }
+
+    #[test]
+    fn extracts_integer_arrays() {
+        let [x, y] = extract_unsigned_integers_array::<u64, 2>("Prize: X=8400, Y=5400");
+        assert_eq!((x, y), (8400, 5400));
+        let [px, py, vx, vy] = extract_signed_integers_array::<i32, 4>("p=6,3 v=-1,-3");
+        assert_eq!((px, py, vx, vy), (6, 3, -1, -3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a Vec of length 3 but it was 2")]
+    fn integer_array_of_wrong_length() {
+        extract_unsigned_integers_array::<u32, 3>("1, 2");
+    }
+
+    #[test]
+    fn integers_that_do_not_fit() {
+        let error = try_extract_unsigned_integers::<u8>("1, 2, 300").unwrap_err();
+        assert_eq!(error.to_string(), "integer \"300\" does not fit in u8");
+        assert!(try_extract_signed_integers::<u32>("1 -2").is_err());
+        assert_eq!(try_extract_signed_integers::<i8>("1 -2").unwrap(), [1, -2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in u8")]
+    fn extracting_integers_that_do_not_fit_panics() {
+        iter_unsigned_integers::<u8>("1, 2, 300").for_each(drop);
+    }
+
+    #[test]
+    fn direction_rotations() {
+        assert_eq!(
+            Direction4::iter()
+                .map(Direction4::turn_right)
+                .collect::<Vec<_>>(),
+            [
+                Direction4::Right,
+                Direction4::Down,
+                Direction4::Left,
+                Direction4::Up
+            ]
+        );
+        for direction in Direction4::iter() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite(), direction.turn_left().turn_left());
+            assert_eq!(
+                Point2D::new(0, 0) + direction.offset() + direction.opposite().offset(),
+                Point2D::new(0, 0)
+            );
+        }
+
+        for direction in Direction8::iter() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_ne!(direction.opposite(), direction);
+        }
+        assert_eq!(Direction8::UpRight.opposite(), Direction8::DownLeft);
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parsed = "^>v<NESW"
+            .chars()
+            .map(|c| Direction4::try_from(c).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed[..4], Direction4::ALL);
+        assert_eq!(parsed[4..], Direction4::ALL);
+        assert_eq!(Direction4::try_from('x'), Err('x'));
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vec2D::new(1, -2);
+        let b = Vec2D::new(3, 4);
+        assert_eq!(a + b, Vec2D::new(4, 2));
+        assert_eq!(a - b, Vec2D::new(-2, -6));
+        assert_eq!(-a, Vec2D::new(-1, 2));
+        assert_eq!(a * 3, Vec2D::new(3, -6));
+        assert_eq!(a * -1, -a);
+    }
+
+    #[test]
+    fn negative_points_are_not_coords() {
+        assert_eq!(
+            Coords2D::try_from(Point2D::new(2, 3)),
+            Ok(Coords2D::new(2, 3))
+        );
+        for point in [Point2D::new(-1, 0), Point2D::new(0, -1)] {
+            assert_eq!(Coords2D::try_from(point), Err(point));
+        }
+    }
+
+    #[test]
+    fn checked_step_stays_in_grid() {
+        let grid = Grid::new(Array2D::filled_with(0, 2, 3));
+        let (top_left, bottom_right) = (Coords2D::new(0, 0), Coords2D::new(1, 2));
+
+        assert_eq!(grid.checked_step(top_left, Direction4::Up.offset()), None);
+        assert_eq!(grid.checked_step(top_left, Direction4::Left.offset()), None);
+        assert_eq!(
+            grid.checked_step(bottom_right, Direction4::Down.offset()),
+            None
+        );
+        assert_eq!(
+            grid.checked_step(bottom_right, Direction4::Right.offset()),
+            None
+        );
+
+        assert_eq!(
+            grid.checked_step(top_left, Direction4::Right.offset()),
+            Some(Coords2D::new(0, 1))
+        );
+        assert_eq!(
+            grid.checked_step(bottom_right, Vec2D::new(-1, -2)),
+            Some(top_left)
+        );
+    }
+
+    #[test]
+    fn grid_lines_of_a_non_square_grid() {
+        fn to_string<'a>(line: impl Iterator<Item = &'a char>) -> String {
+            line.collect()
+        }
+        let grid = "abcd\nefgh\nijkl\n".parse::<Grid<char>>().unwrap();
+
+        assert_eq!(to_string(grid.row_iter(1)), "efgh");
+        assert_eq!(to_string(grid.column_iter(3)), "dhl");
+        assert_eq!(
+            grid.rows_iter().map(to_string).collect::<Vec<_>>(),
+            ["abcd", "efgh", "ijkl"]
+        );
+        assert_eq!(
+            grid.columns_iter().map(to_string).collect::<Vec<_>>(),
+            ["aei", "bfj", "cgk", "dhl"]
+        );
+        assert_eq!(
+            grid.diagonals_iter().map(to_string).collect::<Vec<_>>(),
+            ["i", "ej", "afk", "bgl", "ch", "d"]
+        );
+        assert_eq!(
+            grid.anti_diagonals_iter()
+                .map(to_string)
+                .collect::<Vec<_>>(),
+            ["a", "be", "cfi", "dgj", "hk", "l"]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Declarative parsing of line-based puzzle input, driven by patterns like `"{}: {}"` where every
+//! `{}` is a field and everything else has to match literally.
+
+use crate::{InputError, InputErrorKind};
+
+/// Why a field failed to parse, and where in the (trimmed) field the offending text starts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    pub offset: usize,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(message: String) -> Self {
+        Self { offset: 0, message }
+    }
+}
+
+/// A value that can be parsed from a single field of a [`Pattern`]. Surrounding whitespace is
+/// trimmed before parsing.
+pub trait Field: Sized {
+    fn parse_field(s: &str) -> Result<Self, FieldError>;
+}
+
+macro_rules! impl_field_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl Field for $t {
+                fn parse_field(s: &str) -> Result<Self, FieldError> {
+                    s.parse().map_err(|e| {
+                        FieldError::new(format!("invalid {} {s:?}: {e}", stringify!($t)))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_field_from_str!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String);
+
+/// A list of values separated by commas and/or whitespace, like `75,47,61` or `7 6 4 2 1`.
+/// Errors point at the offending item rather than the start of the list.
+impl<T: Field> Field for Vec<T> {
+    fn parse_field(s: &str) -> Result<Self, FieldError> {
+        s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                T::parse_field(item).map_err(|mut e| {
+                    e.offset += item.as_ptr().addr() - s.as_ptr().addr();
+                    e
+                })
+            })
+            .collect()
+    }
+}
+
+/// The fields of a whole line, as a tuple with one element per `{}` in the pattern.
+pub trait Record: Sized {
+    const NUM_FIELDS: usize;
+
+    /// Parses `fields`, which has exactly [`Self::NUM_FIELDS`] elements. On failure, returns the
+    /// index of the offending field along with the reason.
+    fn from_fields(fields: &[&str]) -> Result<Self, (usize, FieldError)>;
+}
+
+macro_rules! impl_record_for_tuple {
+    ($n:expr; $($t:ident $i:tt),+) => {
+        impl<$($t: Field),+> Record for ($($t,)+) {
+            const NUM_FIELDS: usize = $n;
+
+            fn from_fields(fields: &[&str]) -> Result<Self, (usize, FieldError)> {
+                Ok(($($t::parse_field(fields[$i]).map_err(|e| ($i, e))?,)+))
+            }
+        }
+    };
+}
+
+impl_record_for_tuple!(1; A 0);
+impl_record_for_tuple!(2; A 0, B 1);
+impl_record_for_tuple!(3; A 0, B 1, C 2);
+impl_record_for_tuple!(4; A 0, B 1, C 2, D 3);
+impl_record_for_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+
+/// A line pattern split into the literal text around its `{}` fields.
+#[derive(Clone, Debug)]
+pub struct Pattern<'a> {
+    literals: Vec<&'a str>,
+}
+
+impl<'a> Pattern<'a> {
+    /// Panics if the pattern has no fields, or if two fields are not separated by any text, as
+    /// there would be no telling where the first one ends.
+    pub fn new(pattern: &'a str) -> Self {
+        let literals = pattern.split("{}").collect::<Vec<_>>();
+        assert!(
+            literals.len() >= 2,
+            "Pattern {pattern:?} should have at least one field"
+        );
+        let separators = &literals[1..literals.len() - 1];
+        assert!(
+            separators.iter().all(|literal| !literal.is_empty()),
+            "Fields in pattern {pattern:?} should be separated by some text"
+        );
+
+        Self { literals }
+    }
+
+    pub fn num_fields(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    /// Splits `line` into its fields, each with the byte offset where it starts, or returns
+    /// `None` if the literal text does not match. Fields end at the first occurrence of the text
+    /// following them.
+    pub fn captures<'s>(&self, line: &'s str) -> Option<Vec<(usize, &'s str)>> {
+        let (first, rest) = self.literals.split_first()?;
+        let mut offset = first.len();
+        let mut remaining = line.strip_prefix(first)?;
+        let mut fields = Vec::with_capacity(rest.len());
+
+        for (i, literal) in rest.iter().enumerate() {
+            let is_last = i == rest.len() - 1;
+            let end = match (is_last, literal.is_empty()) {
+                (true, true) => remaining.len(),
+                (true, false) => remaining.strip_suffix(literal)?.len(),
+                (false, _) => remaining.find(literal)?,
+            };
+            fields.push((offset, &remaining[..end]));
+
+            let consumed = end + literal.len();
+            if is_last && consumed != remaining.len() {
+                return None;
+            }
+            offset += consumed;
+            remaining = &remaining[consumed..];
+        }
+
+        Some(fields)
+    }
+
+    /// Parses a single line. Errors carry the column of the offending field, but no line number.
+    pub fn parse<R: Record>(&self, line: &str) -> Result<R, InputError> {
+        assert_eq!(
+            R::NUM_FIELDS,
+            self.num_fields(),
+            "The pattern should have one field per element of the record"
+        );
+
+        let invalid = |message: String| InputError::new(InputErrorKind::InvalidLine(message));
+
+        let captures = self.captures(line).ok_or_else(|| {
+            let pattern = self.literals.join("{}");
+            invalid(format!("line {line:?} does not match pattern {pattern:?}"))
+        })?;
+        let fields = captures
+            .iter()
+            .map(|(_, field)| field.trim())
+            .collect::<Vec<_>>();
+
+        R::from_fields(&fields).map_err(|(i, e)| {
+            let (offset, field) = captures[i];
+            let leading_whitespace = field.len() - field.trim_start().len();
+            let column = line[..offset + leading_whitespace + e.offset]
+                .chars()
+                .count()
+                + 1;
+            let mut error = invalid(e.message);
+            error.column = Some(column);
+            error
+        })
+    }
+}
+
+/// Parses every line of `input` according to `pattern`, e.g.
+/// `parse_lines::<(u64, Vec<u64>)>(input, "{}: {}")` for lines like `3267: 81 40 27`.
+pub fn parse_lines<R: Record>(input: &str, pattern: &str) -> Result<Vec<R>, InputError> {
+    parse_lines_from(input, pattern, 1)
+}
+
+/// Like [`parse_lines`], for a section of a larger input starting on line `first_line`, so that
+/// errors carry the line number within the whole input.
+pub fn parse_lines_from<R: Record>(
+    input: &str,
+    pattern: &str,
+    first_line: usize,
+) -> Result<Vec<R>, InputError> {
+    let pattern = Pattern::new(pattern);
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            pattern.parse(line).map_err(|e| {
+                let column = e.column;
+                e.at(first_line + i, column)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_of_bad_list_item() {
+        let error = parse_lines::<(Vec<u64>,)>("1 2\n3 4\n5 x\n", "{}").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(3)));
+
+        let error = Pattern::new("{}: {}")
+            .parse::<(u64, Vec<u64>)>("190: 10,  19,x")
+            .unwrap_err();
+        assert_eq!(error.column, Some(14));
+    }
+
+    #[test]
+    fn captures_up_to_the_end_of_the_line() {
+        let pattern = Pattern::new("{}: {}");
+        assert_eq!(
+            pattern.captures("3267: 81 40 27"),
+            Some(vec![(0, "3267"), (6, "81 40 27")])
+        );
+        assert_eq!(pattern.captures("3267: "), Some(vec![(0, "3267"), (6, "")]));
+    }
+
+    #[test]
+    fn captures_before_a_suffix() {
+        let pattern = Pattern::new("p={},{}!");
+        assert_eq!(pattern.captures("p=1,2!"), Some(vec![(2, "1"), (4, "2")]));
+        assert_eq!(pattern.captures("p=1,2"), None);
+        assert_eq!(pattern.captures("p=1,2!?"), None);
+    }
+
+    #[test]
+    fn fields_end_at_the_first_separator() {
+        let pattern = Pattern::new("{}|{}");
+        assert_eq!(pattern.captures("1|2|3"), Some(vec![(0, "1"), (2, "2|3")]));
+        assert!(pattern.parse::<(u32, u32)>("1|2|3").is_err());
+    }
+
+    #[test]
+    fn mismatched_literals() {
+        let pattern = Pattern::new("Button A: {}");
+        assert_eq!(pattern.captures("Button B: 1"), None);
+        assert_eq!(pattern.captures("Button A"), None);
+
+        let error = pattern.parse::<(u32,)>("Button B: 1").unwrap_err();
+        assert_eq!(error.column, None);
+    }
+
+    #[test]
+    fn column_of_bad_field() {
+        let error = parse_lines::<(u32, u32)>("47|53\n 4| x\n", "{}|{}").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(5)));
+        assert!(error.to_string().starts_with("2:5: invalid u32 \"x\""));
+    }
+
+    #[test]
+    #[should_panic(expected = "should have at least one field")]
+    fn pattern_without_fields() {
+        Pattern::new("x");
+    }
+}
@@ -0,0 +1,268 @@
+//! Region analysis on 2D arrays: flood fill and connected-component labelling, where a region is
+//! a connected group of cells holding equal values.
+
+use crate::{search::bfs, Coords2D, Direction4, GetNeighbors, Point2D};
+use array2d::Array2D;
+use std::collections::HashSet;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells are connected through their edges only.
+    Four,
+    /// Cells are also connected through their corners.
+    Eight,
+}
+
+/// The smallest rectangle containing a region, with both corners inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub top_left: Coords2D,
+    pub bottom_right: Coords2D,
+}
+
+impl BoundingBox {
+    pub fn height(&self) -> usize {
+        self.bottom_right.row - self.top_left.row + 1
+    }
+
+    pub fn width(&self) -> usize {
+        self.bottom_right.column - self.top_left.column + 1
+    }
+
+    pub fn contains(&self, position: Coords2D) -> bool {
+        (self.top_left.row..=self.bottom_right.row).contains(&position.row)
+            && (self.top_left.column..=self.bottom_right.column).contains(&position.column)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Region<T> {
+    pub value: T,
+    pub cells: Vec<Coords2D>,
+    pub area: usize,
+    /// Number of cell edges on the boundary of the region, including around any holes.
+    pub perimeter: usize,
+    /// Number of straight sides of the boundary, including around any holes.
+    pub sides: usize,
+    pub bounding_box: BoundingBox,
+}
+
+impl<T> Region<T> {
+    fn new(value: T, cells: HashSet<Coords2D>) -> Self {
+        let bounding_box = bounding_box(&cells);
+        let perimeter = perimeter(&cells);
+        let sides = sides(&cells);
+        let mut cells = cells.into_iter().collect::<Vec<_>>();
+        cells.sort_unstable_by_key(|c| (c.row, c.column));
+
+        Self {
+            value,
+            area: cells.len(),
+            cells,
+            perimeter,
+            sides,
+            bounding_box,
+        }
+    }
+}
+
+/// The result of labelling every cell of an array with the index of its region.
+#[derive(Clone, Debug)]
+pub struct Labelling<T> {
+    pub labels: Array2D<usize>,
+    pub regions: Vec<Region<T>>,
+}
+
+impl<T> Labelling<T> {
+    pub fn region_of(&self, position: Coords2D) -> Option<&Region<T>> {
+        let label = self.labels.get(position.row, position.column)?;
+        self.regions.get(*label)
+    }
+}
+
+fn neighbors<T>(
+    array: &Array2D<T>,
+    position: Coords2D,
+    connectivity: Connectivity,
+) -> Vec<Coords2D> {
+    match connectivity {
+        Connectivity::Four => array.get_neighboring_indices_no_diagonal(position),
+        Connectivity::Eight => array.get_neighboring_indices(position),
+    }
+}
+
+/// Returns every cell connected to `start` through cells holding the same value as it.
+pub fn flood_fill<T: PartialEq>(
+    array: &Array2D<T>,
+    start: Coords2D,
+    connectivity: Connectivity,
+) -> HashSet<Coords2D> {
+    let Some(value) = array.get(start.row, start.column) else {
+        return HashSet::new();
+    };
+
+    bfs(start, |&position| {
+        neighbors(array, position, connectivity)
+            .into_iter()
+            .filter(|n| array[(n.row, n.column)] == *value)
+    })
+    .distances
+    .into_keys()
+    .collect()
+}
+
+/// Splits `array` into its regions. Regions are labelled in the row-major order of their
+/// top-left-most cell.
+pub fn label_regions<T: PartialEq + Clone>(
+    array: &Array2D<T>,
+    connectivity: Connectivity,
+) -> Labelling<T> {
+    let mut labels = Array2D::filled_with(usize::MAX, array.num_rows(), array.num_columns());
+    let mut regions = Vec::new();
+
+    for (row, column) in array.indices_row_major() {
+        if labels[(row, column)] != usize::MAX {
+            continue;
+        }
+
+        let cells = flood_fill(array, Coords2D::new(row, column), connectivity);
+        for cell in &cells {
+            labels[(cell.row, cell.column)] = regions.len();
+        }
+        regions.push(Region::new(array[(row, column)].clone(), cells));
+    }
+
+    Labelling { labels, regions }
+}
+
+fn contains(cells: &HashSet<Coords2D>, point: Point2D) -> bool {
+    Coords2D::try_from(point).is_ok_and(|position| cells.contains(&position))
+}
+
+pub fn bounding_box(cells: &HashSet<Coords2D>) -> BoundingBox {
+    let min_row = cells.iter().map(|c| c.row).min().unwrap_or(0);
+    let max_row = cells.iter().map(|c| c.row).max().unwrap_or(0);
+    let min_column = cells.iter().map(|c| c.column).min().unwrap_or(0);
+    let max_column = cells.iter().map(|c| c.column).max().unwrap_or(0);
+
+    BoundingBox {
+        top_left: Coords2D::new(min_row, min_column),
+        bottom_right: Coords2D::new(max_row, max_column),
+    }
+}
+
+pub fn perimeter(cells: &HashSet<Coords2D>) -> usize {
+    cells
+        .iter()
+        .map(|&cell| {
+            Direction4::iter()
+                .filter(|d| !contains(cells, Point2D::from(cell) + d.offset()))
+                .count()
+        })
+        .sum()
+}
+
+/// Counts the straight sides of the boundary of `cells`, which is the same as the number of
+/// its corners.
+pub fn sides(cells: &HashSet<Coords2D>) -> usize {
+    cells
+        .iter()
+        .map(|&cell| {
+            let cell = Point2D::from(cell);
+            Direction4::iter()
+                .filter(|&d| {
+                    // Check the corner between `d` and the direction clockwise from it
+                    let a = d.offset();
+                    let b = d.turn_right().offset();
+                    let has_a = contains(cells, cell + a);
+                    let has_b = contains(cells, cell + b);
+                    let has_diagonal = contains(cells, cell + a + b);
+
+                    let is_outer_corner = !has_a && !has_b;
+                    let is_inner_corner = has_a && has_b && !has_diagonal;
+                    is_outer_corner || is_inner_corner
+                })
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_2d_char_array;
+
+    fn summary(labelling: &Labelling<char>) -> Vec<(char, usize, usize, usize)> {
+        labelling
+            .regions
+            .iter()
+            .map(|r| (r.value, r.area, r.perimeter, r.sides))
+            .collect()
+    }
+
+    #[test]
+    fn small_garden() {
+        let array = read_2d_char_array("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let labelling = label_regions(&array, Connectivity::Four);
+        assert_eq!(
+            summary(&labelling),
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+
+        let c = labelling.region_of(Coords2D::new(3, 3)).unwrap();
+        assert_eq!(c.value, 'C');
+        assert_eq!(
+            c.bounding_box,
+            BoundingBox {
+                top_left: Coords2D::new(1, 2),
+                bottom_right: Coords2D::new(3, 3),
+            }
+        );
+        assert_eq!((c.bounding_box.height(), c.bounding_box.width()), (3, 2));
+        assert!(!c.bounding_box.contains(Coords2D::new(0, 2)));
+        assert_eq!(labelling.labels[(2, 3)], 2);
+    }
+
+    #[test]
+    fn region_with_holes() {
+        let array = read_2d_char_array("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n");
+        let labelling = label_regions(&array, Connectivity::Four);
+        let regions = summary(&labelling);
+        assert_eq!(regions[0], ('O', 21, 36, 20));
+        assert_eq!(regions[1..], [('X', 1, 4, 4); 4]);
+    }
+
+    #[test]
+    fn sides_around_inner_corners() {
+        let array = read_2d_char_array("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n");
+        let labelling = label_regions(&array, Connectivity::Four);
+        assert_eq!(
+            summary(&labelling),
+            [('A', 28, 40, 12), ('B', 4, 8, 4), ('B', 4, 8, 4)]
+        );
+    }
+
+    #[test]
+    fn diagonal_neighbours_with_eight_connectivity() {
+        let array = read_2d_char_array("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n");
+        let labelling = label_regions(&array, Connectivity::Eight);
+        assert_eq!(labelling.regions.len(), 2);
+
+        let b = &labelling.regions[1];
+        assert_eq!((b.value, b.area, b.perimeter), ('B', 8, 16));
+        assert_eq!(
+            flood_fill(&array, Coords2D::new(4, 1), Connectivity::Eight),
+            b.cells.iter().copied().collect()
+        );
+        assert_eq!(
+            flood_fill(&array, Coords2D::new(4, 1), Connectivity::Four).len(),
+            4
+        );
+    }
+}
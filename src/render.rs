@@ -0,0 +1,236 @@
+//! Colored terminal rendering of 2D arrays, with highlighted cells drawn on top of them.
+
+use array2d::Array2D;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::{map_array, Coords2D};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+        }
+    }
+
+    /// The colour as red, green and blue components, for drawing images.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Self::Red => [220, 50, 47],
+            Self::Green => [80, 160, 40],
+            Self::Yellow => [230, 180, 0],
+            Self::Blue => [38, 110, 210],
+            Self::Magenta => [200, 60, 150],
+            Self::Cyan => [40, 170, 170],
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+
+/// A set of cells to highlight, in the order they should appear when animated.
+#[derive(Clone, Debug)]
+pub struct Overlay {
+    pub cells: Vec<Coords2D>,
+    pub color: Color,
+    /// Drawn instead of the cell's own value, if set.
+    pub glyph: Option<char>,
+}
+
+impl Overlay {
+    pub fn new(cells: impl IntoIterator<Item = Coords2D>, color: Color) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+            color,
+            glyph: None,
+        }
+    }
+
+    pub fn with_glyph(mut self, glyph: char) -> Self {
+        self.glyph = Some(glyph);
+        self
+    }
+}
+
+/// Draws an array with overlays on top of it. Later overlays are drawn over earlier ones.
+#[derive(Clone, Debug)]
+pub struct Renderer {
+    cells: Array2D<String>,
+    overlays: Vec<Overlay>,
+}
+
+impl Renderer {
+    pub fn new<T: Display>(array: &Array2D<T>) -> Self {
+        Self {
+            cells: map_array(array, ToString::to_string),
+            overlays: Vec::new(),
+        }
+    }
+
+    pub fn with_overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    pub fn with_overlays(mut self, overlays: impl IntoIterator<Item = Overlay>) -> Self {
+        self.overlays.extend(overlays);
+        self
+    }
+
+    /// The highlighted cells in the order they are revealed, with the overlay of each.
+    fn highlighted_cells(&self) -> impl Iterator<Item = (Coords2D, &Overlay)> {
+        self.overlays
+            .iter()
+            .flat_map(|overlay| overlay.cells.iter().map(move |&cell| (cell, overlay)))
+    }
+
+    fn render_highlights(&self, highlights: &HashMap<Coords2D, &Overlay>) -> String {
+        let mut out = String::new();
+        for (row, cells) in self.cells.rows_iter().enumerate() {
+            for (column, cell) in cells.enumerate() {
+                match highlights.get(&Coords2D::new(row, column)) {
+                    Some(overlay) => {
+                        let code = overlay.color.ansi_code();
+                        match overlay.glyph {
+                            Some(glyph) => out += &format!("\x1b[1;{code}m{glyph}{RESET}"),
+                            None => out += &format!("\x1b[1;{code}m{cell}{RESET}"),
+                        }
+                    }
+                    None => out += &cell.to_string(),
+                }
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn num_highlighted_cells(&self) -> usize {
+        self.overlays.iter().map(|o| o.cells.len()).sum()
+    }
+
+    pub fn render(&self) -> String {
+        self.render_highlights(&self.highlighted_cells().collect())
+    }
+
+    /// Frames that reveal the overlays `cells_per_frame` cells at a time, in order, ending with
+    /// the same picture as [`Self::render`].
+    pub fn frames(&self, cells_per_frame: usize) -> impl Iterator<Item = String> + '_ {
+        let step = cells_per_frame.max(1);
+        let num_frames = self.num_highlighted_cells().div_ceil(step).max(1);
+        // Each frame only adds its own cells to those revealed by the frames before it
+        let mut cells = self.highlighted_cells();
+        let mut highlights = HashMap::new();
+        (0..num_frames).map(move |_| {
+            highlights.extend(cells.by_ref().take(step));
+            self.render_highlights(&highlights)
+        })
+    }
+}
+
+/// Draws `frames` one after another in place, `fps` times per second.
+pub fn animate(
+    frames: impl IntoIterator<Item = String>,
+    fps: f64,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let delay = Duration::try_from_secs_f64(1.0 / fps).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Cannot wait between frames at {fps:?} frames per second"),
+        )
+    })?;
+    write!(out, "{CLEAR_SCREEN}")?;
+    for frame in frames {
+        write!(out, "{CURSOR_HOME}{frame}")?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn renderer() -> Renderer {
+        Renderer::new(&Array2D::from_rows(&[vec!['a', 'b'], vec!['c', 'd']]).unwrap())
+    }
+
+    #[test]
+    fn later_overlay_wins() {
+        let rendered = renderer()
+            .with_overlay(Overlay::new([Coords2D::new(0, 1)], Color::Red))
+            .with_overlay(Overlay::new([Coords2D::new(0, 1)], Color::Blue))
+            .render();
+        assert_eq!(rendered, "a\x1b[1;34mb\x1b[0m\ncd\n");
+    }
+
+    #[test]
+    fn glyph_replaces_cell() {
+        let overlay = Overlay::new([Coords2D::new(1, 0)], Color::Green).with_glyph('#');
+        let rendered = renderer().with_overlay(overlay).render();
+        assert_eq!(rendered, "ab\n\x1b[1;32m#\x1b[0md\n");
+    }
+
+    #[test]
+    fn frames_reveal_cells_in_order() {
+        let renderer = renderer()
+            .with_overlay(Overlay::new(
+                [
+                    Coords2D::new(0, 0),
+                    Coords2D::new(0, 1),
+                    Coords2D::new(1, 0),
+                ],
+                Color::Red,
+            ))
+            .with_overlay(Overlay::new([Coords2D::new(1, 1)], Color::Cyan));
+
+        let frames = renderer.frames(1).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], "\x1b[1;31ma\x1b[0mb\ncd\n");
+        assert_eq!(frames.last(), Some(&renderer.render()));
+
+        let frames = renderer.frames(3).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames.last(), Some(&renderer.render()));
+    }
+
+    #[test]
+    fn rejects_unusable_frame_rates() {
+        for fps in [0.0, -1.0, f64::MIN_POSITIVE, 1e-300, f64::NAN] {
+            let error = animate(renderer().frames(1), fps, &mut io::sink()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn without_overlays() {
+        let renderer = renderer().with_overlay(Overlay::new([], Color::Red));
+        assert_eq!(renderer.render(), "ab\ncd\n");
+        assert_eq!(renderer.frames(1).collect::<Vec<_>>(), ["ab\ncd\n"]);
+    }
+}
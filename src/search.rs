@@ -0,0 +1,466 @@
+//! Graph searches over implicit graphs, where the edges of a node are given by a closure. Grids
+//! can be searched by passing [`orthogonal_neighbors`] or [`all_neighbors`] as that closure.
+
+use crate::{Coords2D, GetNeighbors};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Everything a search learnt about the nodes it reached.
+#[derive(Clone, Debug)]
+pub struct SearchResult<N, C = usize> {
+    /// Distance (or total cost) from the start to every reached node.
+    pub distances: HashMap<N, C>,
+    /// The node each reached node was first reached from. The start has no entry.
+    pub predecessors: HashMap<N, N>,
+    /// Reached nodes in the order the search finished with them.
+    pub order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    pub fn is_reached(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.order.iter()
+    }
+
+    pub fn distance_to(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Walks the predecessors back from `target`, returning the path from the start to it.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.is_reached(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(previous) = self.predecessors.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Visits every node reachable from `start` in breadth-first order, so distances are the
+/// minimal number of steps.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    result.distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = result.distances[&node];
+        for next in neighbors(&node) {
+            if result.is_reached(&next) {
+                continue;
+            }
+            result.distances.insert(next.clone(), distance + 1);
+            result.predecessors.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+        result.order.push(node);
+    }
+
+    result
+}
+
+/// Visits every node reachable from `start` in depth-first order, without recursion.
+/// Distances are the depths in the resulting DFS tree, not shortest distances.
+pub fn dfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut stack = vec![(start, None::<N>, 0)];
+
+    while let Some((node, previous, depth)) = stack.pop() {
+        if result.is_reached(&node) {
+            continue;
+        }
+        result.distances.insert(node.clone(), depth);
+        if let Some(previous) = previous {
+            result.predecessors.insert(node.clone(), previous);
+        }
+
+        let mut next_nodes = neighbors(&node)
+            .into_iter()
+            .filter(|next| !result.is_reached(next))
+            .collect::<Vec<_>>();
+        // Push in reverse so that the first neighbor is explored first
+        next_nodes.reverse();
+        for next in next_nodes {
+            stack.push((next, Some(node.clone()), depth + 1));
+        }
+
+        result.order.push(node);
+    }
+
+    result
+}
+
+/// Finds the cheapest path from `start` to every reachable node. `neighbors` returns each
+/// neighbor together with the (non-negative) cost of moving to it.
+pub fn dijkstra<N, C, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new();
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    // The heap stores indices into `nodes`, so that `N` does not need to be `Ord`
+    let mut nodes = vec![(start, None::<N>)];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, idx))) = heap.pop() {
+        let (node, previous) = nodes[idx].clone();
+        if result.is_reached(&node) || best.get(&node).is_some_and(|&b| b < cost) {
+            continue;
+        }
+        result.distances.insert(node.clone(), cost);
+        if let Some(previous) = previous {
+            result.predecessors.insert(node.clone(), previous);
+        }
+
+        for (next, step_cost) in neighbors(&node) {
+            let next_cost = cost + step_cost;
+            if result.is_reached(&next) || best.get(&next).is_some_and(|&b| b <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            heap.push(Reverse((next_cost, nodes.len())));
+            nodes.push((next, Some(node.clone())));
+        }
+
+        result.order.push(node);
+    }
+
+    result
+}
+
+/// Finds the cheapest path from `start` to the first node satisfying `is_goal`, guided by
+/// `heuristic`. The heuristic must be consistent for the result to be optimal, e.g. the Manhattan
+/// distance on a grid with unit steps. Returns the path, including both ends, and its total cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new();
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut nodes = vec![(start.clone(), None::<N>, C::default())];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+
+    while let Some(Reverse((_, idx))) = heap.pop() {
+        let (node, previous, cost) = nodes[idx].clone();
+        if result.is_reached(&node) || best.get(&node).is_some_and(|&b| b < cost) {
+            continue;
+        }
+        result.distances.insert(node.clone(), cost);
+        if let Some(previous) = previous {
+            result.predecessors.insert(node.clone(), previous);
+        }
+
+        if is_goal(&node) {
+            let path = result
+                .path_to(&node)
+                .expect("Goal should be reached as we just inserted it");
+            return Some((path, cost));
+        }
+
+        for (next, step_cost) in neighbors(&node) {
+            let next_cost = cost + step_cost;
+            if result.is_reached(&next) || best.get(&next).is_some_and(|&b| b <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            heap.push(Reverse((next_cost + heuristic(&next), nodes.len())));
+            nodes.push((next, Some(node.clone()), next_cost));
+        }
+    }
+
+    None
+}
+
+/// Neighbors closure for searching a grid, moving up, down, left and right.
+pub fn orthogonal_neighbors<T>(
+    grid: &impl GetNeighbors<T>,
+) -> impl Fn(&Coords2D) -> Vec<Coords2D> + '_ {
+    |&position| grid.get_neighboring_indices_no_diagonal(position)
+}
+
+/// Neighbors closure for searching a grid, also moving diagonally.
+pub fn all_neighbors<T>(grid: &impl GetNeighbors<T>) -> impl Fn(&Coords2D) -> Vec<Coords2D> + '_ {
+    |&position| grid.get_neighboring_indices(position)
+}
+
+/// Orders every node reachable from `starts` so that each node comes before all of its
+/// neighbors. Returns `None` if a cycle is reachable, as no such order exists then.
+pub fn topological_order<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    enum State {
+        InProgress,
+        Done,
+    }
+
+    let mut states = HashMap::new();
+    let mut post_order = Vec::new();
+
+    for start in starts {
+        if states.contains_key(&start) {
+            continue;
+        }
+        states.insert(start.clone(), State::InProgress);
+        let mut stack = vec![(start.clone(), neighbors(&start).into_iter())];
+
+        while let Some((node, remaining)) = stack.last_mut() {
+            match remaining.next() {
+                Some(next) => match states.get(&next) {
+                    Some(State::InProgress) => return None,
+                    Some(State::Done) => {}
+                    None => {
+                        states.insert(next.clone(), State::InProgress);
+                        let next_neighbors = neighbors(&next).into_iter();
+                        stack.push((next, next_neighbors));
+                    }
+                },
+                None => {
+                    states.insert(node.clone(), State::Done);
+                    post_order.push(node.clone());
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    post_order.reverse();
+    Some(post_order)
+}
+
+/// For every node reachable from `starts` in a DAG, counts the distinct paths from it to nodes
+/// satisfying `is_target`. A path may end at any target, even if it could continue past it.
+/// Each node is only expanded once, so this is linear in the size of the reachable graph.
+/// Returns `None` if a cycle is reachable, as the number of paths could be infinite then.
+pub fn count_paths<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    is_target: impl Fn(&N) -> bool,
+    neighbors: impl FnMut(&N) -> I,
+) -> Option<HashMap<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    count_paths_with_order(starts, is_target, neighbors).map(|(_, counts)| counts)
+}
+
+/// Like [`count_paths`], but also returns the [`topological_order`] the counts were computed in,
+/// for callers that need both.
+pub fn count_paths_with_order<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    is_target: impl Fn(&N) -> bool,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Option<(Vec<N>, HashMap<N, usize>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // Keep the neighbors found while ordering the nodes, so they are not expanded again below
+    let mut adjacency = HashMap::new();
+    let order = topological_order(starts, |node: &N| {
+        let next = neighbors(node).into_iter().collect::<Vec<_>>();
+        adjacency.insert(node.clone(), next.clone());
+        next
+    })?;
+    let mut counts = HashMap::with_capacity(order.len());
+
+    // Every neighbor comes later in the order, so its count is known by the time we need it
+    for node in order.iter().rev() {
+        let through_neighbors = adjacency[node]
+            .iter()
+            .map(|next| counts[next])
+            .sum::<usize>();
+        let count = usize::from(is_target(node)) + through_neighbors;
+        counts.insert(node.clone(), count);
+    }
+
+    Some((order, counts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_2d_number_grid, read_2d_char_array};
+    use array2d::Array2D;
+    use itertools::Itertools;
+    use std::collections::HashSet;
+
+    const MAZE: &str = ".#..\n.#.#\n...#\n##.#\n";
+
+    /// Steps through open cells of `maze`, never into walls.
+    fn open_neighbors(maze: &Array2D<char>) -> impl Fn(&Coords2D) -> Vec<Coords2D> + '_ {
+        let neighbors = orthogonal_neighbors(maze);
+        move |position| {
+            neighbors(position)
+                .into_iter()
+                .filter(|n| maze[(n.row, n.column)] != '#')
+                .collect()
+        }
+    }
+
+    fn is_walkable(maze: &Array2D<char>, path: &[Coords2D]) -> bool {
+        path.iter().all(|p| maze[(p.row, p.column)] != '#')
+            && path
+                .iter()
+                .tuple_windows()
+                .all(|(a, b)| a.row.abs_diff(b.row) + a.column.abs_diff(b.column) == 1)
+    }
+
+    /// Moving into a cell costs the digit in it.
+    fn weighted_neighbors(costs: &Array2D<u8>) -> impl Fn(&Coords2D) -> Vec<(Coords2D, u32)> + '_ {
+        let neighbors = orthogonal_neighbors(costs);
+        move |position| {
+            neighbors(position)
+                .into_iter()
+                .map(|n| (n, u32::from(costs[(n.row, n.column)])))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path_around_a_wall() {
+        let maze = read_2d_char_array(MAZE);
+        let result = bfs(Coords2D::new(0, 0), open_neighbors(&maze));
+        let goal = Coords2D::new(0, 3);
+
+        assert_eq!(result.distance_to(&goal), Some(7));
+        let path = result.path_to(&goal).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], Coords2D::new(0, 0));
+        assert!(is_walkable(&maze, &path));
+    }
+
+    #[test]
+    fn dfs_reaches_the_same_cells_as_bfs() {
+        let maze = read_2d_char_array(MAZE);
+        let start = Coords2D::new(0, 0);
+        let depth_first = dfs(start, open_neighbors(&maze));
+        let breadth_first = bfs(start, open_neighbors(&maze));
+
+        assert_eq!(depth_first.order[0], start);
+        assert_eq!(
+            depth_first.reached().collect::<HashSet<_>>(),
+            breadth_first.reached().collect::<HashSet<_>>()
+        );
+        for cell in depth_first.reached() {
+            let path = depth_first.path_to(cell).unwrap();
+            assert!(is_walkable(&maze, &path));
+            assert_eq!(depth_first.distance_to(cell), Some(path.len() - 1));
+        }
+    }
+
+    #[test]
+    fn dijkstra_takes_a_cheaper_detour() {
+        let costs = parse_2d_number_grid("191\n191\n111\n");
+        let result = dijkstra(Coords2D::new(0, 0), weighted_neighbors(&costs));
+        let goal = Coords2D::new(0, 2);
+
+        // Straight across costs 9 + 1, around the bottom costs 6 steps of 1
+        assert_eq!(result.distance_to(&goal), Some(6));
+        assert_eq!(result.path_to(&goal).unwrap().len(), 7);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let costs = parse_2d_number_grid("1911\n1919\n1119\n9911\n");
+        let start = Coords2D::new(0, 0);
+        let goal = Coords2D::new(3, 3);
+        let manhattan =
+            |p: &Coords2D| (p.row.abs_diff(goal.row) + p.column.abs_diff(goal.column)) as u32;
+
+        let (path, cost) =
+            astar(start, |p| *p == goal, weighted_neighbors(&costs), manhattan).unwrap();
+        let expected = dijkstra(start, weighted_neighbors(&costs));
+
+        assert_eq!(Some(cost), expected.distance_to(&goal));
+        assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+        let path_cost = path[1..]
+            .iter()
+            .map(|p| u32::from(costs[(p.row, p.column)]))
+            .sum::<u32>();
+        assert_eq!(path_cost, cost);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let maze = read_2d_char_array(MAZE);
+        let start = Coords2D::new(0, 0);
+        let walled_off = Coords2D::new(3, 0);
+
+        assert_eq!(bfs(start, open_neighbors(&maze)).path_to(&walled_off), None);
+        let unit_steps = |p: &Coords2D| open_neighbors(&maze)(p).into_iter().map(|n| (n, 1));
+        assert_eq!(dijkstra(start, unit_steps).distance_to(&walled_off), None);
+        assert_eq!(astar(start, |p| *p == walled_off, unit_steps, |_| 0), None);
+    }
+
+    #[test]
+    fn counts_paths_expanding_each_node_once() {
+        // A diamond 0 -> {1, 2} -> 3, plus a shortcut 0 -> 3
+        let edges = HashMap::from([(0, vec![1, 2, 3]), (1, vec![3]), (2, vec![3]), (3, vec![])]);
+        let mut expanded = HashMap::new();
+        let (order, counts) = count_paths_with_order(
+            [0],
+            |&node| node == 3,
+            |node| {
+                *expanded.entry(*node).or_insert(0) += 1;
+                edges[node].clone()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(order.first(), Some(&0));
+        assert_eq!(order.last(), Some(&3));
+        assert_eq!(counts, HashMap::from([(0, 3), (1, 1), (2, 1), (3, 1)]));
+        assert!(expanded.values().all(|&times| times == 1));
+    }
+
+    #[test]
+    fn cycles_have_no_path_counts() {
+        assert_eq!(count_paths([0], |_| false, |&node| [(node + 1) % 3]), None);
+    }
+}
@@ -0,0 +1,103 @@
+use advent_of_code_2024::{
+    days::{find_day, Day, Part},
+    try_read_file_to_string,
+};
+use serde::Deserialize;
+use std::path::Path;
+
+/// The contents of `answers/dayN.toml`.
+#[derive(Deserialize)]
+struct Answers {
+    input: Option<Expected>,
+    #[serde(default)]
+    examples: Vec<Example>,
+}
+
+#[derive(Deserialize)]
+struct Expected {
+    part1: Option<u64>,
+    part2: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct Example {
+    input: String,
+    #[serde(flatten)]
+    expected: Expected,
+}
+
+fn check(day: &Day, input: &str, expected: &Expected, name: &str) {
+    let parts = [(Part::One, expected.part1), (Part::Two, expected.part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| answer.map(|answer| (part, answer.to_string())))
+        .collect::<Vec<_>>();
+    let (to_solve, expected_answers): (Vec<_>, Vec<_>) = parts.into_iter().unzip();
+
+    let answers = day
+        .solve(input, &to_solve)
+        .unwrap_or_else(|e| panic!("Day {} failed on {name}: {e:#}", day.number));
+
+    for ((part, expected), actual) in to_solve.iter().zip(expected_answers).zip(answers) {
+        assert_eq!(
+            actual, expected,
+            "Day {}, part {part} gave the wrong answer for {name}",
+            day.number
+        );
+    }
+}
+
+fn check_day(number: u8) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let day = find_day(number).expect("Day should be registered");
+
+    let manifest = try_read_file_to_string(root.join(format!("answers/day{number}.toml")))
+        .unwrap_or_else(|e| panic!("{e}"));
+    let answers = toml::from_str::<Answers>(&manifest)
+        .unwrap_or_else(|e| panic!("Malformed answers for day {number}: {e}"));
+
+    for (i, example) in answers.examples.iter().enumerate() {
+        check(
+            day,
+            &example.input,
+            &example.expected,
+            &format!("example {}", i + 1),
+        );
+        check(
+            day,
+            &example.input.replace('\n', "\r\n"),
+            &example.expected,
+            &format!("example {} with CRLF line endings", i + 1),
+        );
+    }
+
+    if let Some(expected) = &answers.input {
+        let input =
+            try_read_file_to_string(day.default_input_path()).unwrap_or_else(|e| panic!("{e}"));
+        check(day, &input, expected, "the puzzle input");
+    }
+}
+
+macro_rules! answer_tests {
+    ($($(#[$attr:meta])* $name:ident => $day:literal),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day1 => 1,
+    day2 => 2,
+    day3 => 3,
+    day4 => 4,
+    day5 => 5,
+    day6 => 6,
+    day7 => 7,
+    day8 => 8,
+    day9 => 9,
+    day10 => 10,
+}
@@ -0,0 +1,39 @@
+use advent_of_code_2024::days::{find_day, Part, DAYS};
+
+#[test]
+fn generated_inputs_are_reproducible() {
+    for day in &DAYS {
+        assert_eq!(
+            day.generate(42, Some(20)),
+            day.generate(42, Some(20)),
+            "Day {} gave different inputs for the same seed",
+            day.number
+        );
+        assert_ne!(
+            day.generate(1, Some(20)),
+            day.generate(2, Some(20)),
+            "Day {} ignored the seed",
+            day.number
+        );
+    }
+}
+
+#[test]
+fn generated_inputs_can_be_solved() {
+    for day in &DAYS {
+        for seed in 0..5 {
+            let input = day.generate(seed, Some(30));
+            day.solve(&input, &Part::ALL).unwrap_or_else(|e| {
+                panic!("Day {} failed on input from seed {seed}: {e:#}", day.number)
+            });
+        }
+    }
+}
+
+#[test]
+fn large_generated_day3_input_can_be_solved() {
+    // Sums of 120 products of up to 999 * 999 per line, far more than fit in an i32
+    let day = find_day(3).unwrap();
+    let input = day.generate(1, Some(300));
+    day.solve(&input, &Part::ALL).unwrap();
+}